@@ -0,0 +1,83 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use obadh_engine::{ObadhEngine, Tokenizer};
+
+/// Short everyday words
+const SHORT_WORDS: &[&str] = &["ami", "tumi", "bhalo", "kal", "gan"];
+
+/// Long words with conjuncts, which stress vowel position lookup
+const CONJUNCT_WORDS: &[&str] = &["kkhmo", "songskriti", "bikkhobh", "sOwtontro", "porikkha", "sUrrzo"];
+
+const PARAGRAPH: &str = "ami banglay gan gai, ami banglar gan gai. \
+    amar sonar bangla, ami tomay bhalobasi. \
+    cirodin tomar akash, tomar batas, amar prane bajay bNashi. \
+    bikkhobh o songskriti niye amra kotha boli. ";
+
+fn bench_transliterate(c: &mut Criterion) {
+    let engine = ObadhEngine::new();
+    
+    c.bench_function("transliterate short words", |b| {
+        b.iter(|| {
+            for word in SHORT_WORDS {
+                black_box(engine.transliterate(black_box(word)));
+            }
+        })
+    });
+    
+    c.bench_function("transliterate conjunct words", |b| {
+        b.iter(|| {
+            for word in CONJUNCT_WORDS {
+                black_box(engine.transliterate(black_box(word)));
+            }
+        })
+    });
+    
+    let text = PARAGRAPH.repeat(50);
+    c.bench_function("transliterate paragraphs", |b| {
+        b.iter(|| black_box(engine.transliterate(black_box(&text))))
+    });
+}
+
+fn bench_transliterate_into(c: &mut Criterion) {
+    let engine = ObadhEngine::new();
+    let inputs: Vec<&str> = SHORT_WORDS.iter().copied().cycle().take(1000).collect();
+    
+    // The same short inputs, with a new String per call and with one reused buffer
+    c.bench_function("transliterate 1000 short inputs", |b| {
+        b.iter(|| {
+            for input in &inputs {
+                black_box(engine.transliterate(black_box(input)));
+            }
+        })
+    });
+    
+    c.bench_function("transliterate_into 1000 short inputs", |b| {
+        let mut out = String::new();
+        b.iter(|| {
+            for input in &inputs {
+                engine.transliterate_into(black_box(input), &mut out);
+                black_box(&out);
+            }
+        })
+    });
+}
+
+fn bench_tokenize_word(c: &mut Criterion) {
+    let tokenizer = Tokenizer::new();
+    
+    c.bench_function("tokenize_word conjunct words", |b| {
+        b.iter(|| {
+            for word in CONJUNCT_WORDS {
+                black_box(tokenizer.tokenize_word(black_box(word)));
+            }
+        })
+    });
+}
+
+fn bench_construction(c: &mut Criterion) {
+    c.bench_function("construct engine", |b| {
+        b.iter(|| black_box(ObadhEngine::new()))
+    });
+}
+
+criterion_group!(benches, bench_transliterate, bench_transliterate_into, bench_tokenize_word, bench_construction);
+criterion_main!(benches);
@@ -103,7 +103,7 @@ build_wasm() {
     info "Building WASM Package..."
     
     # Build WebAssembly package
-    wasm-pack build --target web --out-dir pkg || error "Failed to build WebAssembly package"
+    wasm-pack build wasm --target web --out-dir ../pkg --out-name obadh_engine || error "Failed to build WebAssembly package"
     
     # Check for www directory
     if [ ! -d "www" ]; then
@@ -144,7 +144,7 @@ build_dist() {
     
     # First build the WASM package
     info "Building WebAssembly package..."
-    wasm-pack build --target web --out-dir pkg || error "Failed to build WebAssembly package"
+    wasm-pack build wasm --target web --out-dir ../pkg --out-name obadh_engine || error "Failed to build WebAssembly package"
     
     # Change to www directory
     cd www || error "Failed to change to www directory"
@@ -0,0 +1,16 @@
+#![no_main]
+
+use std::sync::OnceLock;
+
+use libfuzzer_sys::fuzz_target;
+use obadh_engine::Tokenizer;
+
+static TOKENIZER: OnceLock<Tokenizer> = OnceLock::new();
+
+fuzz_target!(|word: &str| {
+    let units = TOKENIZER.get_or_init(Tokenizer::new).tokenize_word(word);
+    
+    // Every part of a non-empty word ends up in some unit
+    assert_eq!(units.is_empty(), word.is_empty());
+    assert!(units.iter().all(|unit| !unit.text.is_empty()));
+});
@@ -0,0 +1,27 @@
+#![no_main]
+
+use std::sync::OnceLock;
+
+use libfuzzer_sys::fuzz_target;
+use obadh_engine::engine::Transliterator;
+
+static ENGINE: OnceLock<Transliterator> = OnceLock::new();
+
+fuzz_target!(|text: &str| {
+    let engine = ENGINE.get_or_init(Transliterator::new);
+    let output = engine.transliterate(text);
+    
+    // A cap the output can't reach leaves it whole
+    assert_eq!(engine.transliterate_capped(text, usize::MAX), (output.clone(), false));
+    
+    // A cap it can reach cuts it to a prefix within the cap
+    let (capped, truncated) = engine.transliterate_capped(text, text.len());
+    assert!(capped.len() <= text.len() && output.starts_with(&capped));
+    assert_eq!(truncated, capped.len() < output.len());
+    
+    // The other views of the same text must not panic
+    engine.annotate(text);
+    for word in text.split_whitespace() {
+        engine.syllables(word);
+    }
+});
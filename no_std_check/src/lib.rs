@@ -0,0 +1,71 @@
+//! Build check for the `no_std` core
+//!
+//! Build it with `cargo build --manifest-path no_std_check/Cargo.toml`, or
+//! with `--target` set to a bare-metal target such as `thumbv7em-none-eabihf`.
+//! The build fails if the crate without its `std` feature needs `std`.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+use core::alloc::{GlobalAlloc, Layout};
+use core::cell::UnsafeCell;
+use core::panic::PanicInfo;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use obadh_engine::definitions::{consonants, vowels, Scheme};
+use obadh_engine::{PhoneticUnitType, Tokenizer, TokenType};
+
+/// Whether the tokenizer and the tables work as they do with `std`
+#[no_mangle]
+pub extern "C" fn obadh_no_std_check() -> bool {
+    let tokenizer = Tokenizer::new();
+    let words = tokenizer.tokenize_text("ami bhalo achi.").iter()
+        .filter(|token| token.token_type == TokenType::Word)
+        .count();
+    let units = tokenizer.tokenize_word("kk");
+    
+    words == 3
+        && units.len() == 1 && units[0].unit_type == PhoneticUnitType::Conjunct
+        && consonants().get("k") == Some(&"ক")
+        && vowels().get("i").map(|vowel| vowel.independent) == Some("ই")
+        && Scheme::Itrans.to_avro(String::from("aa")) == "A"
+}
+
+/// A bump allocator over a fixed arena, enough for the check to link
+struct Arena {
+    memory: UnsafeCell<[u8; 1 << 16]>,
+    used: AtomicUsize,
+}
+
+// SAFETY: allocations hand out disjoint ranges claimed through the atomic
+unsafe impl Sync for Arena {}
+
+unsafe impl GlobalAlloc for Arena {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let base = self.memory.get() as usize;
+        let mut used = self.used.load(Ordering::Relaxed);
+        loop {
+            let start = (base + used).next_multiple_of(layout.align()) - base;
+            let end = start + layout.size();
+            if end > 1 << 16 {
+                return core::ptr::null_mut();
+            }
+            match self.used.compare_exchange_weak(used, end, Ordering::Relaxed, Ordering::Relaxed) {
+                Ok(_) => return (base + start) as *mut u8,
+                Err(current) => used = current,
+            }
+        }
+    }
+    
+    unsafe fn dealloc(&self, _ptr: *mut u8, _layout: Layout) {}
+}
+
+#[global_allocator]
+static ALLOCATOR: Arena = Arena { memory: UnsafeCell::new([0; 1 << 16]), used: AtomicUsize::new(0) };
+
+#[panic_handler]
+fn panic(_info: &PanicInfo) -> ! {
+    loop {}
+}
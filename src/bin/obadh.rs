@@ -1,10 +1,14 @@
 use std::io::{self, Read};
 use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
 use std::time::{Instant, Duration};
-use serde_json::{json, Value};
+use serde_json::json;
 use clap::{Command, Arg, ArgAction};
 
-use obadh_engine::engine::{Transliterator, TokenType};
+use obadh_engine::{ObadhEngine, TokenType};
+use obadh_engine::wasm::{PerformanceMetrics, TokenAnalysis, TransliterationResult};
 
 // Single source of version - using the crate version from Cargo.toml
 const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -42,6 +46,38 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .default_missing_value("1")
                 .value_parser(clap::value_parser!(usize))
         )
+        .arg(
+            Arg::new("passthrough")
+                .long("passthrough")
+                .help("Run sanitization and tokenization but return the input unchanged (profiling aid)")
+                .action(ArgAction::SetTrue)
+        )
+        .arg(
+            Arg::new("jobs")
+                .short('j')
+                .long("jobs")
+                .help("Transliterate lines on N threads, keeping the output in input order")
+                .value_parser(clap::value_parser!(usize))
+        )
+        .arg(
+            Arg::new("input-file")
+                .short('i')
+                .long("input-file")
+                .help("Transliterate the text of FILE; repeat to process several files in order")
+                .value_name("FILE")
+                .action(ArgAction::Append)
+                .value_parser(clap::value_parser!(PathBuf))
+                .conflicts_with("INPUT")
+        )
+        .arg(
+            Arg::new("output-file")
+                .short('o')
+                .long("output-file")
+                .help("Write the output to FILE, or to a file of the same name per input file if FILE is a directory or ends with a slash")
+                .value_name("FILE")
+                .value_parser(clap::value_parser!(PathBuf))
+                .conflicts_with_all(["debug", "verbose", "benchmark"])
+        )
         .arg(
             Arg::new("pretty")
                 .short('p')
@@ -49,17 +85,84 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .help("Pretty-print the JSON output (only used with --debug or --verbose)")
                 .action(ArgAction::SetTrue)
         )
+        .subcommand(
+            Command::new("explain")
+                .about("Show how text is tokenized, split into syllables and transliterated")
+                .arg(
+                    Arg::new("TEXT")
+                        .help("Text to explain")
+                        .required(true)
+                        .num_args(1..)
+                )
+        )
+        .args_conflicts_with_subcommands(true)
         .get_matches();
 
     // Get command line flags
     let debug_mode = matches.get_flag("debug");
     let verbose_mode = matches.get_flag("verbose");
     let pretty_print = matches.get_flag("pretty");
+    let passthrough = matches.get_flag("passthrough");
     let benchmark_iterations = matches.get_one::<usize>("benchmark").copied();
+    let jobs = matches.get_one::<usize>("jobs").copied().unwrap_or(1).max(1);
+
+    // Engine diagnostics go to stderr; RUST_LOG (e.g. RUST_LOG=debug) raises the level
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("warn")).init();
 
-    // Get the input text from arguments or stdin
+    if let Some(explain_matches) = matches.subcommand_matches("explain") {
+        let text: Vec<&str> = explain_matches.get_many::<String>("TEXT").unwrap_or_default().map(String::as_str).collect();
+        print!("{}", explain(&ObadhEngine::new(), &text.join(" ")));
+        return Ok(());
+    }
+    
+    let input_files: Vec<&PathBuf> = matches.get_many::<PathBuf>("input-file").unwrap_or_default().collect();
+    let output_file = matches.get_one::<PathBuf>("output-file");
+    
+    // Files are transliterated line by line, so their line endings are kept
+    if !input_files.is_empty() {
+        let engine = ObadhEngine::new().with_passthrough(passthrough);
+        
+        if let Some(dir) = output_file.filter(|path| is_output_dir(path)) {
+            let outputs = input_files.iter()
+                .map(|path| output_path(dir, path))
+                .collect::<io::Result<Vec<_>>>()?;
+            check_outputs(&input_files, &outputs)?;
+            
+            // A missing directory is created only once nothing would be overwritten
+            fs::create_dir_all(dir)?;
+            for (path, output_path) in input_files.iter().zip(outputs) {
+                let output = transliterate_parallel(&engine, &fs::read_to_string(path)?, jobs);
+                fs::write(output_path, output)?;
+            }
+            return Ok(());
+        }
+        
+        if let Some(path) = output_file {
+            check_outputs(&input_files, std::slice::from_ref(path))?;
+        }
+        
+        if !(debug_mode || verbose_mode || benchmark_iterations.is_some()) {
+            let mut output = String::new();
+            for path in input_files {
+                output.push_str(&transliterate_parallel(&engine, &fs::read_to_string(path)?, jobs));
+            }
+            match output_file {
+                Some(path) => fs::write(path, output)?,
+                None => print!("{}", output),
+            }
+            return Ok(());
+        }
+    }
+    
+    // Get the input text from arguments, input files or stdin
     let input = if let Some(text) = matches.get_one::<String>("INPUT") {
         text.clone()
+    } else if !input_files.is_empty() {
+        let mut text = String::new();
+        for path in input_files {
+            text.push_str(&fs::read_to_string(path)?);
+        }
+        text
     } else {
         // Try to read from stdin
         let mut buffer = String::new();
@@ -78,106 +181,170 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         buffer
     };
 
-    // Initialize the transliterator
-    let transliterator = Transliterator::new();
+    // Initialize the engine
+    let engine = ObadhEngine::new().with_passthrough(passthrough);
     
     // Process based on the flags
     if let Some(iterations) = benchmark_iterations {
         // Benchmark mode
-        benchmark(&transliterator, &input, iterations, debug_mode || verbose_mode, pretty_print)
+        benchmark(&engine, &input, iterations, debug_mode || verbose_mode, pretty_print)
     } else if debug_mode || verbose_mode {
         // Debug/verbose mode with JSON output
-        process_json_output(&transliterator, &input, verbose_mode, pretty_print)
+        process_json_output(&engine, &input, verbose_mode, pretty_print)
     } else {
         // Default mode: Simple output with just the transliterated text
-        let result = transliterator.transliterate(&input);
-        println!("{}", result);
+        // Any job count goes line by line, so line endings are handled alike
+        let result = transliterate_parallel(&engine, &input, jobs);
+        match output_file {
+            Some(path) => fs::write(path, result)?,
+            None => println!("{}", result),
+        }
         Ok(())
     }
 }
 
+/// Whether the output path names a directory: an existing one, or any path
+/// ending with a separator, which is created if missing
+fn is_output_dir(path: &Path) -> bool {
+    path.is_dir() || path.to_str().and_then(|path| path.chars().last()).is_some_and(std::path::is_separator)
+}
+
+/// Refuse outputs that would overwrite an input file or each other
+fn check_outputs(inputs: &[&PathBuf], outputs: &[PathBuf]) -> io::Result<()> {
+    let error = |message: String| io::Error::new(io::ErrorKind::InvalidInput, message);
+    let inputs: Vec<PathBuf> = inputs.iter().map(fs::canonicalize).collect::<io::Result<_>>()?;
+    
+    for (index, output) in outputs.iter().enumerate() {
+        // An output that doesn't exist yet can't be one of the inputs
+        if let Ok(resolved) = fs::canonicalize(output) {
+            if inputs.contains(&resolved) {
+                return Err(error(format!("refusing to overwrite input file {}", output.display())));
+            }
+        }
+        if outputs[..index].contains(output) {
+            return Err(error(format!("two input files would be written to {}", output.display())));
+        }
+    }
+    
+    Ok(())
+}
+
+/// The path in the output directory for the output of an input file
+fn output_path(dir: &Path, input: &Path) -> io::Result<PathBuf> {
+    let name = input.file_name()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, format!("not a file: {}", input.display())))?;
+    Ok(dir.join(name))
+}
+
+/// Transliterate the input's lines on `jobs` threads
+///
+/// Each thread takes a contiguous run of lines and the results are joined in
+/// thread order, so the output lines match the input lines one to one. A
+/// single job runs on the current thread.
+fn transliterate_parallel(engine: &ObadhEngine, input: &str, jobs: usize) -> String {
+    let lines: Vec<&str> = input.split_inclusive('\n').collect();
+    if jobs <= 1 {
+        return transliterate_lines(engine, &lines);
+    }
+    let chunk_size = lines.len().div_ceil(jobs).max(1);
+    
+    thread::scope(|scope| {
+        let handles: Vec<_> = lines.chunks(chunk_size)
+            .map(|chunk| scope.spawn(move || transliterate_lines(engine, chunk)))
+            .collect();
+        
+        handles.into_iter()
+            .map(|handle| handle.join().expect("transliteration thread panicked"))
+            .collect()
+    })
+}
+
+/// Transliterate lines one at a time, copying each line ending through
+fn transliterate_lines(engine: &ObadhEngine, lines: &[&str]) -> String {
+    let mut output = String::new();
+    let mut line_output = String::new();
+    for line in lines {
+        // The line ending is copied through rather than sanitized
+        let content = line.trim_end_matches(['\n', '\r']);
+        engine.transliterate_into(content, &mut line_output);
+        output.push_str(&line_output);
+        output.push_str(&line[content.len()..]);
+    }
+    output
+}
+
 /// Process text with JSON output for debug/verbose mode
 fn process_json_output(
-    transliterator: &Transliterator, 
+    engine: &ObadhEngine, 
     input: &str, 
     verbose: bool, 
     pretty_print: bool
 ) -> Result<(), Box<dyn std::error::Error>> {
     // Measure sanitization performance
     let sanitize_start = Instant::now();
-    let _sanitized = transliterator.sanitize(input).unwrap_or_else(|_| input.to_string());
+    let _sanitized = engine.sanitize(input).unwrap_or_else(|_| input.to_string());
     let sanitize_duration = sanitize_start.elapsed();
     
     // Measure tokenization performance
     let tokenize_start = Instant::now();
-    let tokens = transliterator.tokenize(input);
+    let tokens = engine.tokenize(input);
     let tokenize_duration = tokenize_start.elapsed();
     
     // Measure transliteration performance
     let transliterate_start = Instant::now();
-    let transliterated = transliterator.transliterate(input);
+    let transliterated = engine.transliterate(input);
     let transliterate_duration = transliterate_start.elapsed();
     
-    // Create JSON output
-    let mut output_json = json!({
-        "input": input,
-        "output": transliterated,
-        "performance": {
-            "total_ms": format_duration(sanitize_duration + tokenize_duration + transliterate_duration),
-            "sanitize_ms": format_duration(sanitize_duration),
-            "tokenize_ms": format_duration(tokenize_duration),
-            "transliterate_ms": format_duration(transliterate_duration),
-        }
-    });
-    
-    // Add token analysis for verbose mode
-    if verbose {
-        if let Value::Object(ref mut map) = output_json {
-            // Convert tokens to JSON structure with detailed analysis
-            let token_analysis = tokens.iter().map(|token| {
-                let mut token_json = json!({
-                    "content": token.content,
-                    "type": format!("{:?}", token.token_type),
-                    "position": token.position
-                });
-                
-                // If it's a word, include phonetic analysis
-                if token.token_type == TokenType::Word {
-                    let phonetic_units = transliterator.tokenize_phonetic(&token.content);
-                    let units_json = phonetic_units.iter().map(|unit| {
-                        json!({
-                            "text": unit.text,
-                            "type": format!("{:?}", unit.unit_type),
-                            "position": unit.position
-                        })
-                    }).collect::<Vec<_>>();
-                    
-                    if let Value::Object(ref mut token_map) = token_json {
-                        token_map.insert("phonetic_units".to_string(), json!(units_json));
-                        
-                        // Add the transliterated form of this word
-                        let word_transliterated = transliterator.transliterate(&token.content);
-                        token_map.insert("transliterated".to_string(), json!(word_transliterated));
-                    }
-                }
-                
-                token_json
-            }).collect::<Vec<_>>();
-            
-            map.insert("token_analysis".to_string(), json!(token_analysis));
-        }
-    }
+    // The result struct fixes the key order, keeping the JSON stable for diffing
+    let output = TransliterationResult {
+        input: input.to_string(),
+        output: transliterated,
+        performance: Some(PerformanceMetrics {
+            total_ms: format_duration(sanitize_duration + tokenize_duration + transliterate_duration),
+            sanitize_ms: format_duration(sanitize_duration),
+            tokenize_ms: format_duration(tokenize_duration),
+            transliterate_ms: format_duration(transliterate_duration),
+        }),
+        // Add token analysis for verbose mode
+        token_analysis: verbose.then(|| TokenAnalysis::from_tokens(engine, &tokens, true)),
+    };
     
     // Output the result
     if pretty_print {
-        println!("{}", serde_json::to_string_pretty(&output_json)?);
+        println!("{}", serde_json::to_string_pretty(&output)?);
     } else {
-        println!("{}", serde_json::to_string(&output_json)?);
+        println!("{}", serde_json::to_string(&output)?);
     }
     
     Ok(())
 }
 
+/// Describe each step of the transliteration of `text` as an indented tree
+fn explain(engine: &ObadhEngine, text: &str) -> String {
+    let mut tree = String::new();
+    tree.push_str(&format!("input     {}\n", text));
+    tree.push_str(&format!("output    {}\n", engine.transliterate(text)));
+    tree.push_str(&format!("phonemes  {}\n", engine.to_ipa(text)));
+    tree.push_str("tokens\n");
+    
+    for token in engine.tokenize_only(text) {
+        tree.push_str(&format!("  {:?} {:?} at {}\n", token.token_type, token.content, token.position));
+        if token.token_type != TokenType::Word {
+            continue;
+        }
+        
+        for syllable in engine.syllables(&token.content) {
+            let kind = if syllable.is_open() { "open" } else { "closed" };
+            tree.push_str(&format!("    syllable {:?} {} ({})\n", syllable.roman, syllable.bengali, kind));
+            for unit in &syllable.units {
+                tree.push_str(&format!("      {:?} {:?}\n", unit.unit_type, unit.text));
+            }
+        }
+    }
+    
+    tree
+}
+
 /// Format Duration to milliseconds with decimal precision
 fn format_duration(duration: Duration) -> f64 {
     duration.as_secs_f64() * 1000.0
@@ -185,7 +352,7 @@ fn format_duration(duration: Duration) -> f64 {
 
 /// Run benchmark with multiple iterations
 fn benchmark(
-    transliterator: &Transliterator, 
+    engine: &ObadhEngine, 
     input: &str, 
     iterations: usize, 
     json_output: bool,
@@ -201,21 +368,25 @@ fn benchmark(
     for _ in 0..iterations {
         // Measure sanitization
         let start = Instant::now();
-        let _ = transliterator.sanitize(input);
-        sanitize_duration += start.elapsed();
+        let _ = engine.sanitize(input);
+        let sanitize_time = start.elapsed();
         
         // Measure tokenization
         let start = Instant::now();
-        let _ = transliterator.tokenize(input);
-        tokenize_duration += start.elapsed();
+        let _ = engine.tokenize(input);
+        let tokenize_time = start.elapsed();
         
         // Measure transliteration
         let start = Instant::now();
-        let _ = transliterator.transliterate(input);
-        transliterate_duration += start.elapsed();
+        let _ = engine.transliterate(input);
+        let transliterate_time = start.elapsed();
         
-        // Total time
-        total_duration += sanitize_duration + tokenize_duration + transliterate_duration;
+        sanitize_duration += sanitize_time;
+        tokenize_duration += tokenize_time;
+        transliterate_duration += transliterate_time;
+        
+        // Total time of this iteration only
+        total_duration += sanitize_time + tokenize_time + transliterate_time;
     }
     
     // Calculate averages
@@ -224,8 +395,14 @@ fn benchmark(
     let avg_tokenize = tokenize_duration / iterations as u32;
     let avg_transliterate = transliterate_duration / iterations as u32;
     
+    // The averages only differ by the nanoseconds lost to integer division
+    debug_assert!(
+        avg_total.abs_diff(avg_sanitize + avg_tokenize + avg_transliterate) <= Duration::from_nanos(3),
+        "average total {:?} is not the sum of the component averages", avg_total
+    );
+    
     // Output benchmark results
-    let transliterated = transliterator.transliterate(input);
+    let transliterated = engine.transliterate(input);
     
     if json_output {
         // JSON output for benchmark results
@@ -3,9 +3,11 @@
 //! This file contains the mappings for Bengali consonants, organized by their phonetic categories,
 //! as well as information about conjunct formation.
 
-use std::collections::HashMap;
+use alloc::{vec, vec::Vec};
+use phf::phf_map;
 
 /// Organizes consonants by their phonetic groups (vargas) and characteristics
+#[derive(Clone)]
 pub struct ConsonantSystem {
     /// Velar consonants (k-varga)
     pub velars: Vec<(&'static str, &'static str)>,
@@ -41,6 +43,7 @@ pub fn consonant_system() -> ConsonantSystem {
         palatals: vec![
             ("c", "চ"),    // ca
             ("ch", "ছ"),   // cha
+            ("chh", "ছ"),  // alternative for cha
             ("J", "জ"),    // ja
             ("j", "জ"),    // ja
             ("jh", "ঝ"),   // jha
@@ -102,22 +105,27 @@ pub fn consonant_system() -> ConsonantSystem {
     }
 }
 
+/// All Bengali consonants, the groups of [`consonant_system`] flattened
+static CONSONANTS: phf::Map<&'static str, &'static str> = phf_map! {
+    // Velars
+    "k" => "ক", "kh" => "খ", "g" => "গ", "gh" => "ঘ", "Ng" => "ঙ",
+    // Palatals
+    "c" => "চ", "ch" => "ছ", "chh" => "ছ", "J" => "জ", "j" => "জ", "jh" => "ঝ", "NG" => "ঞ",
+    // Retroflexes
+    "T" => "ট", "Th" => "ঠ", "D" => "ড", "Dh" => "ঢ", "N" => "ণ",
+    // Dentals
+    "t" => "ত", "th" => "থ", "d" => "দ", "dh" => "ধ", "n" => "ন",
+    // Labials
+    "p" => "প", "ph" => "ফ", "f" => "ফ", "b" => "ব", "bh" => "ভ", "v" => "ভ", "m" => "ম",
+    // Semivowels and liquids
+    "z" => "য", "r" => "র", "l" => "ল",
+    // Fricatives
+    "sh" => "শ", "S" => "শ", "Sh" => "ষ", "s" => "স", "h" => "হ",
+    // Special
+    "R" => "ড়", "Rh" => "ঢ়", "y" => "য়", "Y" => "য়",
+};
+
 /// Returns a flattened map of all Bengali consonants
-pub fn consonants() -> HashMap<&'static str, &'static str> {
-    let system = consonant_system();
-    let mut map = HashMap::new();
-    
-    // Add all consonants from each category
-    for (roman, bengali) in system.velars.iter()
-        .chain(system.palatals.iter())
-        .chain(system.retroflexes.iter())
-        .chain(system.dentals.iter())
-        .chain(system.labials.iter())
-        .chain(system.semivowels.iter())
-        .chain(system.fricatives.iter())
-        .chain(system.special.iter()) {
-            map.insert(*roman, *bengali);
-        }
-    
-    map
-} 
\ No newline at end of file
+pub fn consonants() -> &'static phf::Map<&'static str, &'static str> {
+    &CONSONANTS
+}
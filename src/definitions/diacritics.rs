@@ -3,24 +3,24 @@
 //! This file contains mappings for Bengali diacritics like
 //! hasanta (virama), visarga, chandrabindu, etc.
 
-use std::collections::HashMap;
+use phf::phf_map;
 
-/// Returns a map of Bengali diacritics
-pub fn diacritics() -> HashMap<&'static str, &'static str> {
-    let mut map = HashMap::new();
-    
+static DIACRITICS: phf::Map<&'static str, &'static str> = phf_map! {
     // Hasanta (virama) - suppresses the inherent vowel
     // Note: In Avro, when ",," is followed by non-whitespace, it acts as "o" sound
     // and terminates both conjunct formation and vowel modification
-    map.insert(",,", "্");   // Hasant/Virama
+    ",," => "্",   // Hasant/Virama
     
     // Nasalization
-    map.insert("^", "ঁ");    // Chandrabindu
+    "^" => "ঁ",    // Chandrabindu
     
     // Other diacritics
-    map.insert(":", "ঃ");    // Visarga
-    map.insert("T``", "ৎ");   // Khanda Ta
-    map.insert("ng", "ং");   // Khanda Ta
-    
-    map
+    ":" => "ঃ",    // Visarga
+    "T``" => "ৎ",   // Khanda Ta
+    "ng" => "ং",   // Khanda Ta
+};
+
+/// Returns a map of Bengali diacritics
+pub fn diacritics() -> &'static phf::Map<&'static str, &'static str> {
+    &DIACRITICS
 } 
\ No newline at end of file
@@ -0,0 +1,21 @@
+//! Fallback spellings for ASCII letters without a mapping of their own
+//!
+//! Avro reads letters that have no meaning of their own case-insensitively,
+//! and `q` and `x` as `k` and `ks`. Each letter here is rewritten to the Avro
+//! input it stands for before a word is tokenized. `w` and `W` are left out,
+//! as their reading depends on the letter before them: after a consonant `w`
+//! is the bo-fola, and elsewhere the tokenizer reads it as `O` (ও).
+
+use phf::phf_map;
+
+static ASCII_FALLBACK: phf::Map<char, &'static str> = phf_map! {
+    'q' => "k", 'Q' => "k",
+    'x' => "ks", 'X' => "ks",
+    'B' => "b", 'C' => "c", 'E' => "e", 'F' => "f", 'G' => "g", 'H' => "h",
+    'K' => "k", 'L' => "l", 'M' => "m", 'P' => "p", 'V' => "v", 'Z' => "z",
+};
+
+/// Returns the map of unmapped ASCII letters to the Avro input they stand for
+pub fn ascii_fallback() -> &'static phf::Map<char, &'static str> {
+    &ASCII_FALLBACK
+}
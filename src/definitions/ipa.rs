@@ -0,0 +1,42 @@
+//! Broad IPA for Bengali letters
+//!
+//! This file maps Bengali consonants, vowels, vowel signs and modifiers to a
+//! broad IPA transcription of standard Bengali. Letters that merged in
+//! pronunciation share a transcription (ণ and ন, শ and ষ).
+
+use phf::phf_map;
+
+static IPA: phf::Map<&'static str, &'static str> = phf_map! {
+    // Velars
+    "ক" => "k", "খ" => "kʰ", "গ" => "ɡ", "ঘ" => "ɡʱ", "ঙ" => "ŋ",
+    // Palatals
+    "চ" => "tʃ", "ছ" => "tʃʰ", "জ" => "dʒ", "ঝ" => "dʒʱ", "ঞ" => "n",
+    // Retroflexes
+    "ট" => "ʈ", "ঠ" => "ʈʰ", "ড" => "ɖ", "ঢ" => "ɖʱ", "ণ" => "n",
+    // Dentals
+    "ত" => "t̪", "থ" => "t̪ʰ", "দ" => "d̪", "ধ" => "d̪ʱ", "ন" => "n", "ৎ" => "t̪",
+    // Labials
+    "প" => "p", "ফ" => "pʰ", "ব" => "b", "ভ" => "bʱ", "ম" => "m",
+    // Semivowels and liquids
+    "য" => "dʒ", "র" => "r", "ল" => "l",
+    // Fricatives
+    "শ" => "ʃ", "ষ" => "ʃ", "স" => "s", "হ" => "h",
+    // Letters with a nukta, decomposed as the engine writes them and precomposed
+    "ড\u{09BC}" => "ɽ", "ঢ\u{09BC}" => "ɽʱ", "য\u{09BC}" => "j",
+    "\u{09DC}" => "ɽ", "\u{09DD}" => "ɽʱ", "\u{09DF}" => "j",
+    
+    // Independent vowels
+    "অ" => "ɔ", "আ" => "a", "ই" => "i", "ঈ" => "i", "উ" => "u", "ঊ" => "u",
+    "ঋ" => "ri", "এ" => "e", "ঐ" => "oi̯", "ও" => "o", "ঔ" => "ou̯",
+    // Vowel signs
+    "া" => "a", "ি" => "i", "ী" => "i", "ু" => "u", "ূ" => "u",
+    "ৃ" => "ri", "ে" => "e", "ৈ" => "oi̯", "ো" => "o", "ৌ" => "ou̯",
+    
+    // Modifiers; the chandrabindu nasalizes the vowel before it instead
+    "ং" => "ŋ", "ঃ" => "h",
+};
+
+/// Returns a map of Bengali letters and signs to broad IPA
+pub fn ipa() -> &'static phf::Map<&'static str, &'static str> {
+    &IPA
+}
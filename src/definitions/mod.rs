@@ -2,6 +2,18 @@
 //!
 //! This module contains all character definitions and mappings used in the transliteration process,
 //! organized by linguistic categories.
+//!
+//! The lookup tables are `phf` maps built at compile time, so the functions
+//! returning them cost nothing to call.
+
+/// Map type of the tables derived at runtime: a `HashMap` with the `std`
+/// feature and a `BTreeMap` without it
+#[cfg(feature = "std")]
+pub type Map<K, V> = std::collections::HashMap<K, V>;
+/// Map type of the tables derived at runtime: a `HashMap` with the `std`
+/// feature and a `BTreeMap` without it
+#[cfg(not(feature = "std"))]
+pub type Map<K, V> = alloc::collections::BTreeMap<K, V>;
 
 pub mod consonants;
 pub mod vowels;
@@ -9,11 +21,16 @@ pub mod diacritics;
 pub mod symbols;
 pub mod modifiers;
 pub mod numerals;
+pub mod schemes;
+pub mod fallback;
+pub mod ipa;
 
 // Re-export commonly used functions
 pub use consonants::{consonants, consonant_system, ConsonantSystem};
-pub use vowels::{vowels, independent_vowels, vowel_modifiers, BengaliVowel};
+pub use vowels::{vowels, independent_vowels, vowel_modifiers, vowel_vowel_combinations, BengaliVowel};
 pub use diacritics::diacritics;
-pub use symbols::symbols;
-pub use numerals::numerals;
-pub use modifiers::special_rules; 
\ No newline at end of file
+pub use symbols::{symbols, SHARED_PUNCTUATION};
+pub use numerals::{numerals, ordinal_suffix};
+pub use modifiers::special_rules;
+pub use schemes::Scheme;
+pub use fallback::ascii_fallback; 
\ No newline at end of file
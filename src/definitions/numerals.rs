@@ -1,24 +1,38 @@
 //! Definitions for Bengali numerals
 //!
-//! This file contains mappings for Bengali numerals (০-৯).
+//! This file contains mappings for Bengali numerals (০-৯) and the suffixes
+//! written after them to form ordinals.
 
-use std::collections::HashMap;
+use phf::phf_map;
 
-/// Returns a map of Latin numerals to Bengali numerals
-pub fn numerals() -> HashMap<&'static str, &'static str> {
-    let mut map = HashMap::new();
-    
+static NUMERALS: phf::Map<&'static str, &'static str> = phf_map! {
     // Map Latin digits to Bengali digits
-    map.insert("0", "০");
-    map.insert("1", "১");
-    map.insert("2", "২");
-    map.insert("3", "৩");
-    map.insert("4", "৪");
-    map.insert("5", "৫");
-    map.insert("6", "৬");
-    map.insert("7", "৭");
-    map.insert("8", "৮");
-    map.insert("9", "৯");
-    
-    map
-} 
\ No newline at end of file
+    "0" => "০",
+    "1" => "১",
+    "2" => "২",
+    "3" => "৩",
+    "4" => "৪",
+    "5" => "৫",
+    "6" => "৬",
+    "7" => "৭",
+    "8" => "৮",
+    "9" => "৯",
+};
+
+/// Returns a map of Latin numerals to Bengali numerals
+pub fn numerals() -> &'static phf::Map<&'static str, &'static str> {
+    &NUMERALS
+}
+
+/// Ordinal suffixes of 1 to 10, which follow the spelled ordinals (প্রথম,
+/// দ্বিতীয়, তৃতীয়, চতুর্থ, ...) and so are irregular
+static ORDINAL_SUFFIXES: [&str; 10] = ["ম", "য়", "য়", "র্থ", "ম", "ষ্ঠ", "ম", "ম", "ম", "ম"];
+
+/// Returns the suffix written after the digits of `n` to form its ordinal,
+/// e.g. "য়" for 2 (২য়) and "তম" for 21 (২১তম)
+pub fn ordinal_suffix(n: u64) -> &'static str {
+    match n {
+        1..=10 => ORDINAL_SUFFIXES[n as usize - 1],
+        _ => "তম",
+    }
+}
@@ -0,0 +1,114 @@
+//! Definitions for Romanization input schemes
+//!
+//! The engine's native input is the Avro-style scheme used throughout the other
+//! definition files. Every other scheme is a mapping table from its own input
+//! sequences to the equivalent Avro sequences, applied before tokenization.
+
+use alloc::string::String;
+
+/// ITRANS-like input sequences with their Avro equivalents
+const ITRANS_KEYS: &[(&str, &str)] = &[
+    // Vowels ('a' is the inherent vowel, like Avro's 'o')
+    ("a", "o"),
+    ("aa", "A"),
+    ("i", "i"),
+    ("ii", "I"),
+    ("ee", "I"),
+    ("u", "u"),
+    ("uu", "U"),
+    ("oo", "U"),
+    ("e", "e"),
+    ("ai", "OI"),
+    ("o", "O"),
+    ("au", "OU"),
+    ("RRi", "rri"),
+    ("R^i", "rri"),
+
+    // Consonants that differ from Avro
+    ("~N", "Ng"),
+    ("~n", "NG"),
+    ("JN", "NG"),
+    ("ch", "c"),
+    ("Ch", "ch"),
+    ("chh", "ch"),
+    ("y", "z"),
+    ("Y", "y"),
+    ("v", "b"),
+    ("w", "b"),
+    ("sh", "S"),
+    (".D", "R"),
+    (".Dh", "Rh"),
+    ("x", "kkh"),
+    ("kSh", "kkh"),
+    ("GY", "gg"),
+    ("j~n", "gg"),
+
+    // Diacritics
+    (".n", "ng"),
+    ("M", "ng"),
+    (".N", "^"),
+    ("H", ":"),
+    (".h", ",,"),
+];
+
+/// A Romanization scheme the input is written in
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Scheme {
+    /// Avro-style phonetic input (default)
+    #[default]
+    Avro,
+    /// ITRANS-like input, e.g. `~N` for ঙ, `.n` for the anusvara, `aa` for আ
+    Itrans,
+}
+
+impl Scheme {
+    /// The scheme's input sequences that differ from Avro, with their Avro equivalents
+    ///
+    /// Sequences not listed are the same in both schemes.
+    pub fn keys(&self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Scheme::Avro => &[],
+            Scheme::Itrans => ITRANS_KEYS,
+        }
+    }
+
+    /// Characters the scheme uses beyond the default sanitizer set
+    pub fn extra_chars(&self) -> &'static [char] {
+        match self {
+            Scheme::Avro => &[],
+            Scheme::Itrans => &['~'],
+        }
+    }
+
+    /// Rewrite text in this scheme to the equivalent Avro input
+    ///
+    /// Sequences are matched greedily, longest first, left to right.
+    pub fn to_avro(&self, text: String) -> String {
+        let keys = self.keys();
+        if keys.is_empty() {
+            return text;
+        }
+
+        let mut converted = String::with_capacity(text.len());
+        let mut rest = text.as_str();
+
+        while let Some(c) = rest.chars().next() {
+            let longest = keys.iter()
+                .filter(|(key, _)| rest.starts_with(key))
+                .max_by_key(|(key, _)| key.len());
+
+            match longest {
+                Some((key, avro)) => {
+                    converted.push_str(avro);
+                    rest = &rest[key.len()..];
+                },
+                None => {
+                    converted.push(c);
+                    rest = &rest[c.len_utf8()..];
+                }
+            }
+        }
+
+        converted
+    }
+}
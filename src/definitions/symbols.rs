@@ -2,15 +2,24 @@
 //!
 //! This file contains mappings for Bengali special symbols and punctuation.
 
-use std::collections::HashMap;
+use phf::phf_map;
 
-/// Returns a map of Bengali punctuation and special symbols
-pub fn symbols() -> HashMap<&'static str, &'static str> {
-    let mut map = HashMap::new();
-    
+static SYMBOLS: phf::Map<&'static str, &'static str> = phf_map! {
     // Punctuation
-    map.insert(".", "।");     // Bengali full stop (Dari)
-    map.insert("$", "৳");      // BDT symbol
-    
-    map
-} 
\ No newline at end of file
+    "." => "।",     // Bengali full stop (Dari)
+    "$" => "৳",      // BDT symbol
+};
+
+/// Returns a map of Bengali punctuation and special symbols
+///
+/// This is the single table used for punctuation conversion. Punctuation
+/// without an entry is written the same way in Bengali.
+pub fn symbols() -> &'static phf::Map<&'static str, &'static str> {
+    &SYMBOLS
+}
+
+/// Punctuation written the same way in Bengali text
+///
+/// Other ASCII punctuation, such as `#` or `@`, has no place in Bengali text
+/// and is reported by strict transliteration.
+pub const SHARED_PUNCTUATION: &str = ",;:!?'\"()[]{}-/%+=*&<>"; 
\ No newline at end of file
@@ -3,7 +3,8 @@
 //! This file contains the mappings for Bengali vowels in both their 
 //! independent forms and dependent forms (vowel signs/kars).
 
-use std::collections::HashMap;
+use phf::phf_map;
+use super::Map;
 
 /// A complete Bengali vowel with both independent and dependent forms
 pub struct BengaliVowel {
@@ -15,40 +16,65 @@ pub struct BengaliVowel {
 
 impl BengaliVowel {
     /// Create a new Bengali vowel with both forms
-    pub fn new(independent: &'static str, dependent: Option<&'static str>) -> Self {
+    pub const fn new(independent: &'static str, dependent: Option<&'static str>) -> Self {
         Self { independent, dependent }
     }
 }
 
-/// Returns a map of Bengali vowels with their independent and dependent forms
-pub fn vowels() -> HashMap<&'static str, BengaliVowel> {
-    let mut map = HashMap::new();
-    
+static VOWELS: phf::Map<&'static str, BengaliVowel> = phf_map! {
     // Inherent vowel (no visible kar when used with consonants)
-    map.insert("o", BengaliVowel::new("অ", None));
+    "o" => BengaliVowel::new("অ", None),
     
     // The remaining vowels have both independent and dependent forms
-    map.insert("A", BengaliVowel::new("আ", Some("া")));
-    map.insert("a", BengaliVowel::new("আ", Some("া")));
-    map.insert("i", BengaliVowel::new("ই", Some("ি")));
-    map.insert("I", BengaliVowel::new("ঈ", Some("ী")));
-    map.insert("u", BengaliVowel::new("উ", Some("ু")));
-    map.insert("U", BengaliVowel::new("ঊ", Some("ূ")));
-    map.insert("e", BengaliVowel::new("এ", Some("ে")));
-    map.insert("OI", BengaliVowel::new("ঐ", Some("ৈ")));
-    map.insert("O", BengaliVowel::new("ও", Some("ো")));
-    map.insert("OU", BengaliVowel::new("ঔ", Some("ৌ")));
-    map.insert("rri", BengaliVowel::new("ঋ", Some("ৃ")));
+    "A" => BengaliVowel::new("আ", Some("া")),
+    "a" => BengaliVowel::new("আ", Some("া")),
+    "i" => BengaliVowel::new("ই", Some("ি")),
+    "I" => BengaliVowel::new("ঈ", Some("ী")),
+    "u" => BengaliVowel::new("উ", Some("ু")),
+    "U" => BengaliVowel::new("ঊ", Some("ূ")),
+    "e" => BengaliVowel::new("এ", Some("ে")),
+    // Multi-letter vowels combine greedily ("OI" -> ঐ, "OU" -> ঔ). A dot between
+    // the letters marks a hiatus that keeps them apart ("O.I" -> ওঈ).
+    "OI" => BengaliVowel::new("ঐ", Some("ৈ")),
+    "O" => BengaliVowel::new("ও", Some("ো")),
+    "OU" => BengaliVowel::new("ঔ", Some("ৌ")),
+    "rri" => BengaliVowel::new("ঋ", Some("ৃ")),
     
-    map
+    // A doubled vowel letter is one vowel, not two: "aa" -> আ, "ii" and
+    // "ee" -> ঈ, "uu" -> ঊ, and "oo" -> উ as in English "boot". A dot keeps
+    // the letters apart ("ki.i" -> কিই).
+    "aa" => BengaliVowel::new("আ", Some("া")),
+    "ii" => BengaliVowel::new("ঈ", Some("ী")),
+    "ee" => BengaliVowel::new("ঈ", Some("ী")),
+    "uu" => BengaliVowel::new("ঊ", Some("ূ")),
+    "oo" => BengaliVowel::new("উ", Some("ু")),
+};
+
+// A vowel typed straight after another vowel of the same word is normally
+// written as its full letter ("eo" -> এও). The vowels below are instead
+// written with a য় glide carrying their sign ("ia" -> ইয়া).
+static VOWEL_VOWEL_COMBINATIONS: phf::Map<&'static str, &'static str> = phf_map! {
+    "a" => "য়া",
+    "A" => "য়া",
+    "aa" => "য়া",
+};
+
+/// Returns a map of Bengali vowels with their independent and dependent forms
+pub fn vowels() -> &'static phf::Map<&'static str, BengaliVowel> {
+    &VOWELS
+}
+
+/// Returns a map of the vowels written with a য় glide after another vowel
+pub fn vowel_vowel_combinations() -> &'static phf::Map<&'static str, &'static str> {
+    &VOWEL_VOWEL_COMBINATIONS
 }
 
 /// Returns only the independent vowels for convenience
-pub fn independent_vowels() -> HashMap<&'static str, &'static str> {
+pub fn independent_vowels() -> Map<&'static str, &'static str> {
     let vowels_map = vowels();
-    let mut map = HashMap::new();
+    let mut map = Map::new();
     
-    for (key, value) in vowels_map.iter() {
+    for (key, value) in vowels_map.entries() {
         map.insert(*key, value.independent);
     }
     
@@ -56,11 +82,11 @@ pub fn independent_vowels() -> HashMap<&'static str, &'static str> {
 }
 
 /// Returns only the vowel modifiers (kars) for convenience
-pub fn vowel_modifiers() -> HashMap<&'static str, &'static str> {
+pub fn vowel_modifiers() -> Map<&'static str, &'static str> {
     let vowels_map = vowels();
-    let mut map = HashMap::new();
+    let mut map = Map::new();
     
-    for (key, value) in vowels_map.iter() {
+    for (key, value) in vowels_map.entries() {
         if let Some(dependent) = value.dependent {
             map.insert(*key, dependent);
         }
@@ -0,0 +1,73 @@
+//! Dictionary of whole-word transliterations
+//!
+//! This module provides a simple word list that supplements the rule-based
+//! transliteration for common words whose spelling the rules can't infer.
+
+use std::collections::HashMap;
+
+/// The built-in word list, embedded at compile time
+#[cfg(feature = "builtin-dict")]
+const BUILTIN_DICTIONARY: &str = include_str!("../../data/dictionary.tsv");
+
+/// A map of Roman words to their Bengali spelling
+#[derive(Debug, Clone, Default)]
+pub struct Dictionary {
+    /// Map of Roman words to Bengali words
+    entries: HashMap<String, String>,
+}
+
+impl Dictionary {
+    /// Create an empty dictionary
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Create the built-in dictionary of common words
+    ///
+    /// The word list lives in `data/dictionary.tsv` and is only embedded in the
+    /// binary when the `builtin-dict` feature is enabled.
+    #[cfg(feature = "builtin-dict")]
+    pub fn builtin() -> Self {
+        Self::from_tsv(BUILTIN_DICTIONARY)
+    }
+    
+    /// Parse a dictionary from `roman<TAB>bengali` lines
+    ///
+    /// Blank lines, lines starting with `#` and lines without a tab are skipped.
+    pub fn from_tsv(data: &str) -> Self {
+        let mut dictionary = Self::new();
+        
+        for line in data.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            
+            if let Some((roman, bengali)) = line.split_once('\t') {
+                dictionary.insert(roman.trim(), bengali.trim());
+            }
+        }
+        
+        dictionary
+    }
+    
+    /// Add or replace an entry
+    pub fn insert(&mut self, roman: &str, bengali: &str) {
+        self.entries.insert(roman.to_string(), bengali.to_string());
+    }
+    
+    /// Look up the Bengali spelling of a Roman word
+    pub fn get(&self, roman: &str) -> Option<&str> {
+        self.entries.get(roman).map(String::as_str)
+    }
+    
+    /// Number of entries in the dictionary
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+    
+    /// Check whether the dictionary has no entries
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
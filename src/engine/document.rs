@@ -0,0 +1,70 @@
+//! Document-scoped transliteration
+//!
+//! Words recur throughout a document, so each distinct word is transliterated
+//! once and its output reused for the rest of the document.
+
+use std::collections::HashMap;
+use super::tokenizer::TokenType;
+use super::transliterator::Transliterator;
+
+/// A transliterator that memoizes the output of each word it has seen
+///
+/// Create one per document or request; the memo is dropped with it, so there
+/// is no global cache to bound or invalidate.
+pub struct DocumentTransliterator<'a> {
+    /// The transliterator doing the actual work
+    transliterator: &'a Transliterator,
+    /// Bengali output of every word transliterated so far
+    words: HashMap<String, String>,
+    /// Number of words served from the memo
+    hits: usize,
+}
+
+impl<'a> DocumentTransliterator<'a> {
+    /// Create a document transliterator with an empty memo
+    pub fn new(transliterator: &'a Transliterator) -> Self {
+        Self {
+            transliterator,
+            words: HashMap::new(),
+            hits: 0,
+        }
+    }
+    
+    /// Transliterate a piece of the document
+    ///
+    /// The output is identical to [`Transliterator::transliterate`].
+    pub fn transliterate(&mut self, text: &str) -> String {
+        let Self { transliterator, words, hits } = self;
+        let mut result = String::new();
+        
+        transliterator.transliterate_tokens_into(text, &mut result, |token, out| {
+            if token.token_type != TokenType::Word {
+                transliterator.transliterate_token_into(token, out);
+            } else if let Some(bengali) = words.get(&token.content) {
+                *hits += 1;
+                #[cfg(feature = "metrics")]
+                transliterator.stats.record_cache_hit();
+                out.push_str(bengali);
+            } else {
+                let start = out.len();
+                transliterator.transliterate_token_into(token, out);
+                words.insert(token.content.clone(), out[start..].to_string());
+            }
+        });
+        
+        #[cfg(feature = "metrics")]
+        transliterator.stats.record_call(text, &result);
+        
+        result
+    }
+    
+    /// The number of words served from the memo instead of being transliterated
+    pub fn cache_hits(&self) -> usize {
+        self.hits
+    }
+    
+    /// The number of distinct words in the memo
+    pub fn cached_words(&self) -> usize {
+        self.words.len()
+    }
+}
@@ -0,0 +1,93 @@
+//! Harness for scoring the engine against reference outputs
+//!
+//! This module runs a corpus of `roman<TAB>bengali` pairs, such as an export
+//! from the reference Avro implementation, through the transliterator and
+//! reports how many match.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::{Serialize, Deserialize};
+
+use super::Transliterator;
+
+/// A case whose transliteration differs from the expected output
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Mismatch {
+    /// Line number of the case in the corpus (1-based)
+    pub line: usize,
+    /// Roman input
+    pub roman: String,
+    /// Expected Bengali output
+    pub expected: String,
+    /// Actual Bengali output
+    pub actual: String,
+}
+
+/// Result of running a corpus of cases
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestReport {
+    /// Number of cases run
+    pub total: usize,
+    /// Number of cases that matched the expected output
+    pub passed: usize,
+    /// Number of cases that did not match
+    pub failed: usize,
+    /// Every failing case, in corpus order
+    pub mismatches: Vec<Mismatch>,
+}
+
+impl TestReport {
+    /// Score `roman<TAB>bengali` lines with the given transliterator
+    ///
+    /// Blank lines, lines starting with `#` and lines without a tab are skipped.
+    pub fn from_tsv(transliterator: &Transliterator, data: &str) -> Self {
+        let mut report = Self::default();
+        
+        for (index, line) in data.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            
+            let Some((roman, expected)) = line.split_once('\t') else {
+                continue;
+            };
+            let (roman, expected) = (roman.trim(), expected.trim());
+            
+            let actual = transliterator.transliterate(roman);
+            report.total += 1;
+            
+            if actual == expected {
+                report.passed += 1;
+            } else {
+                report.failed += 1;
+                report.mismatches.push(Mismatch {
+                    line: index + 1,
+                    roman: roman.to_string(),
+                    expected: expected.to_string(),
+                    actual,
+                });
+            }
+        }
+        
+        report
+    }
+    
+    /// The first failing case, if any
+    pub fn first_mismatch(&self) -> Option<&Mismatch> {
+        self.mismatches.first()
+    }
+    
+    /// Check whether every case passed
+    pub fn all_passed(&self) -> bool {
+        self.failed == 0
+    }
+}
+
+/// Read a TSV corpus from `path` and score it with the default transliterator
+pub fn run_cases(path: &Path) -> io::Result<TestReport> {
+    let data = fs::read_to_string(path)?;
+    Ok(TestReport::from_tsv(&Transliterator::new(), &data))
+}
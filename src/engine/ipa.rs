@@ -0,0 +1,118 @@
+//! Pronunciation hints in IPA
+//!
+//! Words are transliterated as usual and the Bengali is read letter by letter
+//! into a broad IPA transcription, e.g. for a speech synthesizer that takes
+//! IPA input or as a hint for screen readers.
+
+use crate::definitions::ipa;
+use super::tokenizer::TokenType;
+use super::transliterator::Transliterator;
+
+/// The inherent vowel অ, spoken after a consonant without a vowel sign
+const INHERENT: &str = "ɔ";
+
+impl Transliterator {
+    /// Transcribe Roman text into broad IPA
+    ///
+    /// Each word is transliterated, then read as Bengali: aspirated stops
+    /// are written with ʰ or ʱ, retroflexes with ʈ, ɖ and ɽ, and a vowel
+    /// with a chandrabindu is nasalized (চাঁদ is tʃãd̪). The inherent vowel is
+    /// ɔ, and is dropped at the end of a word with another vowel. A ya-phala
+    /// or bo-fola lengthens the consonant it follows. Everything other than
+    /// words is kept as typed.
+    pub fn to_ipa(&self, text: &str) -> String {
+        let mut ipa = String::with_capacity(text.len() * 2);
+        let mut bengali = String::new();
+        
+        for token in self.tokenize_only(text) {
+            if token.token_type == TokenType::Word {
+                bengali.clear();
+                self.transliterate_token_into(&token, &mut bengali);
+                push_word_ipa(&bengali, &mut ipa);
+            } else {
+                ipa.push_str(&token.content);
+            }
+        }
+        
+        ipa
+    }
+}
+
+/// Append the IPA of a Bengali word
+fn push_word_ipa(word: &str, out: &mut String) {
+    let chars: Vec<char> = word.chars().collect();
+    let table = ipa::ipa();
+    
+    // A consonant waiting for its vowel, and whether the word has one yet
+    let mut pending = false;
+    let mut has_vowel = false;
+    
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        
+        // A ya-phala or bo-fola lengthens the consonant before it
+        if c == '্' {
+            let phala = matches!(chars.get(i + 1), Some('য' | 'ব')) && chars.get(i + 2) != Some(&'\u{09BC}');
+            if phala && pending {
+                out.push('ː');
+                i += 2;
+            } else {
+                pending = false;
+                i += 1;
+            }
+            continue;
+        }
+        
+        if c == 'ঁ' {
+            if pending {
+                out.push_str(INHERENT);
+                pending = false;
+            }
+            out.push('\u{0303}');
+            i += 1;
+            continue;
+        }
+        
+        // A letter with a nukta is read as one
+        let len = if chars.get(i + 1) == Some(&'\u{09BC}') { 2 } else { 1 };
+        let letter: String = chars[i..i + len].iter().collect();
+        i += len;
+        
+        if is_vowel_sign(c) {
+            pending = false;
+            has_vowel = true;
+        } else if pending {
+            out.push_str(INHERENT);
+            pending = false;
+            has_vowel = true;
+        }
+        
+        match table.get(letter.as_str()) {
+            Some(ipa) => out.push_str(ipa),
+            // Anything else, such as a digit, is kept as written
+            None => out.push_str(&letter),
+        }
+        
+        if is_consonant(c) {
+            pending = true;
+        } else if matches!(c, '\u{0985}'..='\u{0994}') {
+            has_vowel = true;
+        }
+    }
+    
+    // A lone consonant is still spoken with its inherent vowel
+    if pending && !has_vowel {
+        out.push_str(INHERENT);
+    }
+}
+
+/// Whether a character is a Bengali consonant that carries the inherent vowel
+fn is_consonant(c: char) -> bool {
+    matches!(c, '\u{0995}'..='\u{09B9}' | '\u{09DC}' | '\u{09DD}' | '\u{09DF}')
+}
+
+/// Whether a character is a Bengali dependent vowel sign
+fn is_vowel_sign(c: char) -> bool {
+    matches!(c, '\u{09BE}'..='\u{09CC}')
+}
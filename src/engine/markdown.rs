@@ -0,0 +1,89 @@
+//! Markdown transliteration
+//!
+//! Only the prose of a Markdown document is transliterated. The document is
+//! parsed to find its text nodes, and each is replaced in place in the
+//! source, so headings, emphasis markers, link targets and code keep their
+//! original formatting.
+
+use std::iter::once;
+use std::ops::Range;
+use pulldown_cmark::{Event, LinkType, Options, Parser, Tag, TagEnd};
+use super::transliterator::Transliterator;
+
+impl Transliterator {
+    /// Transliterate the prose of a Markdown document, keeping its structure
+    ///
+    /// Text in paragraphs, headings, lists, tables, emphasis and link display
+    /// text is transliterated. Code blocks, inline code, HTML, link and image
+    /// targets, autolinks and bare `http://`, `https://` and `www.` URLs are
+    /// left untouched.
+    pub fn transliterate_markdown(&self, md: &str) -> String {
+        let mut options = Options::empty();
+        options.insert(Options::ENABLE_TABLES);
+        options.insert(Options::ENABLE_STRIKETHROUGH);
+        
+        let mut prose: Vec<Range<usize>> = Vec::new();
+        let mut code_depth = 0usize;
+        let mut autolink_depth = 0usize;
+        
+        for (event, range) in Parser::new_ext(md, options).into_offset_iter() {
+            match event {
+                Event::Start(Tag::CodeBlock(_)) => code_depth += 1,
+                Event::End(TagEnd::CodeBlock) => code_depth = code_depth.saturating_sub(1),
+                // The display text of an autolink is its URL
+                Event::Start(Tag::Link { link_type: LinkType::Autolink | LinkType::Email, .. }) => autolink_depth += 1,
+                Event::End(TagEnd::Link) if autolink_depth > 0 => autolink_depth -= 1,
+                // Text that differs from its source, e.g. from an escape or an
+                // entity, is kept as typed rather than transliterated
+                Event::Text(text) if code_depth == 0 && autolink_depth == 0 && md[range.clone()] == *text => {
+                    prose.push(range);
+                },
+                _ => {},
+            }
+        }
+        
+        let mut result = String::with_capacity(md.len() * 3);
+        let mut last_end = 0;
+        
+        for range in prose {
+            // Consecutive text events can share source, e.g. around an escape
+            if range.start < last_end {
+                continue;
+            }
+            result.push_str(&md[last_end..range.start]);
+            self.transliterate_prose_into(&md[range.clone()], &mut result);
+            last_end = range.end;
+        }
+        
+        result.push_str(&md[last_end..]);
+        result
+    }
+    
+    /// Transliterate a text node, keeping any bare URLs in it unchanged
+    fn transliterate_prose_into(&self, text: &str, out: &mut String) {
+        let mut segment_start = 0;
+        let mut word_start = None;
+        
+        for (i, c) in text.char_indices().chain(once((text.len(), ' '))) {
+            match (c.is_whitespace(), word_start) {
+                (false, None) => word_start = Some(i),
+                (true, Some(start)) => {
+                    if is_bare_url(&text[start..i]) {
+                        out.push_str(&self.transliterate(&text[segment_start..start]));
+                        out.push_str(&text[start..i]);
+                        segment_start = i;
+                    }
+                    word_start = None;
+                },
+                _ => {},
+            }
+        }
+        
+        out.push_str(&self.transliterate(&text[segment_start..]));
+    }
+}
+
+/// Whether a word of prose is a URL
+fn is_bare_url(word: &str) -> bool {
+    ["http://", "https://", "www."].iter().any(|prefix| word.starts_with(prefix))
+}
@@ -1,9 +1,35 @@
 //! Engine module for the Obadh transliteration system
+//!
+//! Only the tokenizer is available without the `std` feature.
 
+#[cfg(feature = "std")]
 pub mod transliterator;
+#[cfg(feature = "std")]
 pub mod sanitizer;
 pub mod tokenizer;
+#[cfg(feature = "std")]
+pub mod dictionary;
+#[cfg(feature = "std")]
+pub mod document;
+#[cfg(feature = "std")]
+pub mod harness;
+#[cfg(feature = "std")]
+mod ipa;
+#[cfg(feature = "markdown")]
+mod markdown;
+#[cfg(feature = "metrics")]
+pub mod stats;
 
-pub use transliterator::Transliterator;
-pub use sanitizer::{Sanitizer, SanitizeResult};
-pub use tokenizer::{Tokenizer, Token, TokenType, PhoneticUnit, PhoneticUnitType};
\ No newline at end of file
+#[cfg(feature = "std")]
+pub use transliterator::{Transliterator, Annotation, CaseFoldProfile, ConjunctProfile, ConjunctType, FinalConsonantPolicy, MappingConflict, NumberFormatOptions, Script, SourceMap, Syllable, TypographyOptions, UnknownPolicy, UnmappableError, ViramaStyle, VowelForm, Warning, WarningKind};
+#[cfg(feature = "std")]
+pub use sanitizer::{Sanitizer, SanitizeError, SanitizeResult};
+#[cfg(feature = "std")]
+pub use dictionary::Dictionary;
+#[cfg(feature = "std")]
+pub use document::DocumentTransliterator;
+#[cfg(feature = "std")]
+pub use harness::{run_cases, TestReport, Mismatch};
+#[cfg(feature = "metrics")]
+pub use stats::{Stats, StatsSnapshot};
+pub use tokenizer::{ApostrophePolicy, Tokenizer, Token, TokenIter, TokenType, PhoneticUnit, PhoneticUnitType};
\ No newline at end of file
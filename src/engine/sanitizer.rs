@@ -8,7 +8,47 @@ use std::collections::HashSet;
 /// Result of sanitization, containing either the sanitized string or an error message
 pub type SanitizeResult = Result<String, String>;
 
+/// A disallowed character found while validating input
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("invalid character '{character}' at position {position}")]
+pub struct SanitizeError {
+    /// The disallowed character
+    pub character: char,
+    /// Byte offset of the character in the input
+    pub position: usize,
+}
+
+/// Punctuation and symbols allowed besides ASCII letters and digits
+const DEFAULT_SYMBOLS: [char; 32] = [
+    ' ', ',', '.', ':', ';', '!', '?', '(', ')', '[', ']', '{', '}',
+    '"', '\'', '`', '-', '_', '+', '=', '/', '\\', '|', '@', '#',
+    '$', '%', '^', '&', '*', '<', '>',
+];
+
+/// The default allowed set, see [`Sanitizer::default_allowed_chars`]
+static DEFAULT_ALLOWED_CHARS: [char; 26 + 26 + 10 + DEFAULT_SYMBOLS.len()] = {
+    let mut chars = [' '; 26 + 26 + 10 + DEFAULT_SYMBOLS.len()];
+    let mut i = 0;
+    while i < 26 {
+        chars[i] = (b'a' + i as u8) as char;
+        chars[26 + i] = (b'A' + i as u8) as char;
+        i += 1;
+    }
+    let mut i = 0;
+    while i < 10 {
+        chars[52 + i] = (b'0' + i as u8) as char;
+        i += 1;
+    }
+    let mut i = 0;
+    while i < DEFAULT_SYMBOLS.len() {
+        chars[62 + i] = DEFAULT_SYMBOLS[i];
+        i += 1;
+    }
+    chars
+};
+
 /// Sanitizer for input text
+#[derive(Clone)]
 pub struct Sanitizer {
     /// Set of allowed characters
     allowed_chars: HashSet<char>,
@@ -17,31 +57,16 @@ pub struct Sanitizer {
 impl Sanitizer {
     /// Create a new sanitizer with the default allowed character set
     pub fn new() -> Self {
-        let mut allowed_chars = HashSet::new();
-        
-        // Add lowercase English letters (a-z)
-        for c in 'a'..='z' {
-            allowed_chars.insert(c);
-        }
-        
-        // Add uppercase English letters (A-Z)
-        for c in 'A'..='Z' {
-            allowed_chars.insert(c);
-        }
-        
-        // Add numerals (0-9)
-        for c in '0'..='9' {
-            allowed_chars.insert(c);
-        }
-        
-        // Add common punctuation and symbols used in Avro transliteration
-        for c in [' ', ',', '.', ':', ';', '!', '?', '(', ')', '[', ']', '{', '}', 
-                  '"', '\'', '`', '-', '_', '+', '=', '/', '\\', '|', '@', '#', 
-                  '$', '%', '^', '&', '*', '<', '>'] {
-            allowed_chars.insert(c);
-        }
-        
-        Sanitizer { allowed_chars }
+        Sanitizer { allowed_chars: DEFAULT_ALLOWED_CHARS.iter().copied().collect() }
+    }
+    
+    /// The characters a new sanitizer allows, in a fixed order
+    ///
+    /// ASCII letters (a-z, A-Z) and digits, followed by the space and the
+    /// punctuation and symbols used in Avro notation, such as `,` (hasant
+    /// `,,`), `^` (chandrabindu), `:` (visarga) and `` ` `` (Khanda Ta).
+    pub fn default_allowed_chars() -> &'static [char] {
+        &DEFAULT_ALLOWED_CHARS
     }
     
     /// Add additional allowed characters to the sanitizer
@@ -75,6 +100,18 @@ impl Sanitizer {
         Ok(input.to_string())
     }
     
+    /// Find every disallowed character in the input in a single pass
+    ///
+    /// Unlike `sanitize`, which only reports that the input is invalid, this returns
+    /// each offending character with its position, in input order. An empty result
+    /// means the input is valid.
+    pub fn validate(&self, input: &str) -> Vec<SanitizeError> {
+        input.char_indices()
+            .filter(|(_, c)| !self.allowed_chars.contains(c))
+            .map(|(position, character)| SanitizeError { character, position })
+            .collect()
+    }
+    
     /// Remove invalid characters from the input and return the sanitized string
     pub fn clean(&self, input: &str) -> String {
         input.chars()
@@ -0,0 +1,78 @@
+//! Usage statistics accumulated across transliterations
+//!
+//! The counters are atomics, so an engine shared between threads records
+//! every call without locking.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Counters updated by each transliteration
+#[derive(Debug, Default)]
+pub struct Stats {
+    calls: AtomicU64,
+    chars_in: AtomicU64,
+    chars_out: AtomicU64,
+    unknown_fragments: AtomicU64,
+    cache_hits: AtomicU64,
+}
+
+/// The counters of [`Stats`] at one point in time
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct StatsSnapshot {
+    /// Number of texts transliterated
+    pub calls: u64,
+    /// Characters of input, summed over every call
+    pub chars_in: u64,
+    /// Characters of output, summed over every call
+    pub chars_out: u64,
+    /// Phonetic units without a Bengali mapping, rendered by the unknown policy
+    pub unknown_fragments: u64,
+    /// Words a [`DocumentTransliterator`](super::DocumentTransliterator) served from its memo
+    pub cache_hits: u64,
+}
+
+impl Stats {
+    /// Record one transliterated text
+    pub(crate) fn record_call(&self, input: &str, output: &str) {
+        self.calls.fetch_add(1, Ordering::Relaxed);
+        self.chars_in.fetch_add(input.chars().count() as u64, Ordering::Relaxed);
+        self.chars_out.fetch_add(output.chars().count() as u64, Ordering::Relaxed);
+    }
+    
+    /// Record a unit without a Bengali mapping
+    pub(crate) fn record_unknown_fragment(&self) {
+        self.unknown_fragments.fetch_add(1, Ordering::Relaxed);
+    }
+    
+    /// Record a word served from a document memo
+    pub(crate) fn record_cache_hit(&self) {
+        self.cache_hits.fetch_add(1, Ordering::Relaxed);
+    }
+    
+    /// Read the counters
+    ///
+    /// Each counter is read atomically, but calls running concurrently may be
+    /// counted in some counters and not yet in others.
+    pub fn snapshot(&self) -> StatsSnapshot {
+        StatsSnapshot {
+            calls: self.calls.load(Ordering::Relaxed),
+            chars_in: self.chars_in.load(Ordering::Relaxed),
+            chars_out: self.chars_out.load(Ordering::Relaxed),
+            unknown_fragments: self.unknown_fragments.load(Ordering::Relaxed),
+            cache_hits: self.cache_hits.load(Ordering::Relaxed),
+        }
+    }
+    
+    /// Set every counter back to zero
+    pub fn reset(&self) {
+        for counter in [&self.calls, &self.chars_in, &self.chars_out, &self.unknown_fragments, &self.cache_hits] {
+            counter.store(0, Ordering::Relaxed);
+        }
+    }
+}
+
+/// A clone counts its own calls, starting from zero
+impl Clone for Stats {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
@@ -3,13 +3,17 @@
 //! This module provides functionality to tokenize input text into words
 //! and letters/phonemes for processing by the transliteration engine.
 
-use std::collections::HashMap;
+use alloc::{format, vec::Vec, string::{String, ToString}};
+use serde::{Serialize, Deserialize};
 use crate::definitions::{
-    consonants, vowels, diacritics, special_rules
+    ascii_fallback, consonants, vowels, vowel_vowel_combinations, diacritics, special_rules, Map
 };
 
+/// Runs of punctuation read as a single token, so typography can replace them whole
+const PUNCTUATION_RUNS: [&str; 4] = ["...", "?!", "!?", "--"];
+
 /// Types of tokens that can be identified
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
 pub enum TokenType {
     /// A standard word token
     Word,
@@ -21,10 +25,12 @@ pub enum TokenType {
     Number,
     /// A special symbol
     Symbol,
+    /// A word kept as typed, such as a protected term
+    Foreign,
 }
 
 /// A token from the input text
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Token {
     /// The content of the token
     pub content: String,
@@ -35,7 +41,7 @@ pub struct Token {
 }
 
 /// Represents a sequence of phonetic components that make up a word
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PhoneticUnit {
     /// The original text
     pub text: String,
@@ -45,8 +51,87 @@ pub struct PhoneticUnit {
     pub position: usize,
 }
 
+impl PhoneticUnit {
+    /// The unit's Roman spelling as a user would type it
+    ///
+    /// Drops the `,,` the tokenizer inserts between the consonants of a
+    /// conjunct, so "k,,k" is shown as "kk". A trailing hasant, typed to end a
+    /// word on a bare consonant, keeps its `,,`, as does a standalone hasant unit.
+    pub fn roman_display(&self) -> String {
+        match self.text.strip_suffix(",,") {
+            Some(base) => format!("{},,", base.replace(",,", "")),
+            None => self.text.replace(",,", ""),
+        }
+    }
+    
+    /// Whether the unit carries a chandrabindu (nasalization)
+    pub fn has_chandrabindu(&self) -> bool {
+        use PhoneticUnitType::*;
+        
+        match self.unit_type {
+            ChandrabinduWithVowel | ChandrabinduWithConsonant | ChandrabinduWithConsonantAndVowel => true,
+            SpecialForm => self.text == "^",
+            _ => false,
+        }
+    }
+    
+    /// Whether the unit is a visarga
+    pub fn has_visarga(&self) -> bool {
+        self.unit_type == PhoneticUnitType::SpecialForm && self.text == ":"
+    }
+    
+    /// Whether the unit is a conjunct of two or more consonants, with or without a vowel
+    ///
+    /// This includes special-rule clusters such as "kkh" (ক্ষ).
+    pub fn is_conjunct(&self) -> bool {
+        use PhoneticUnitType::*;
+        
+        match self.unit_type {
+            Conjunct | ConjunctWithVowel | ConjunctWithTerminator => true,
+            SpecialForm => self.special_rule().is_some_and(|cluster| {
+                cluster.chars().filter(|c| ('\u{0995}'..='\u{09B9}').contains(c)).count() >= 2
+            }),
+            _ => false,
+        }
+    }
+    
+    /// Whether the unit carries a reph (র্), either over a consonant or standalone
+    pub fn has_reph(&self) -> bool {
+        use PhoneticUnitType::*;
+        
+        match self.unit_type {
+            RephOverConsonant | RephOverConsonantWithVowel | RephOverConsonantWithTerminator => true,
+            SpecialForm => self.text == "rr",
+            _ => false,
+        }
+    }
+    
+    /// Whether the unit forms a written syllable (akshara) of its own
+    ///
+    /// Marks that modify a neighbouring unit, such as the hasant, bo-fola,
+    /// anusvara, visarga and standalone chandrabindu, don't count, and neither
+    /// do unknown units. Counting the units of a word for which this holds
+    /// gives its number of written syllables.
+    pub fn is_syllable(&self) -> bool {
+        use PhoneticUnitType::*;
+        
+        match self.unit_type {
+            SpecialForm => self.special_rule().is_some(),
+            // A consonant with its hasant is written, a standalone hasant is not
+            ConsonantWithHasant => self.text != ",,",
+            BoFola | Numeral | Symbol | Unknown => false,
+            _ => true,
+        }
+    }
+    
+    /// The Bengali cluster of a special-rule unit such as "kkh"
+    fn special_rule(&self) -> Option<&'static str> {
+        special_rules().get(self.text.as_str()).copied()
+    }
+}
+
 /// Types of phonetic units in Bengali transliteration
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
 pub enum PhoneticUnitType {
     /// Single consonant
     Consonant,
@@ -78,6 +163,8 @@ pub enum PhoneticUnitType {
     ChandrabinduWithConsonant,
     /// Chandrabindu (ঁ) with a consonant and vowel
     ChandrabinduWithConsonantAndVowel,
+    /// A bo-fola (ব-ফলা), written as 'w' directly after a consonant
+    BoFola,
     /// A special form (e.g., reph, ya-phala, etc.)
     SpecialForm,
     /// A numeral
@@ -88,36 +175,64 @@ pub enum PhoneticUnitType {
     Unknown,
 }
 
+/// How an apostrophe inside a word, as in "amar'i", is handled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ApostrophePolicy {
+    /// The apostrophe is punctuation that splits the word, giving "আমার'ই"
+    Punctuation,
+    /// The apostrophe is dropped and the word read as if it weren't there, giving "আমারি"
+    Drop,
+    /// The apostrophe is dropped, and the letters after it start a new
+    /// syllable: a vowel is written in its full form and no conjunct is
+    /// formed across it, giving "আমারই"
+    #[default]
+    SyllableBreak,
+}
+
 /// Tokenizer for processing input text
+#[derive(Clone)]
 pub struct Tokenizer {
-    /// Map of special sequences to recognize
-    special_sequences: HashMap<String, PhoneticUnitType>,
-    /// Map of vowel patterns 
-    vowel_patterns: HashMap<String, bool>,
-    /// Map of consonant patterns
-    consonant_patterns: HashMap<String, bool>,
+    /// Special sequences to recognize, longest first and then alphabetically
+    special_sequences: Vec<(String, PhoneticUnitType)>,
+    /// Vowel patterns, longest first and then alphabetically
+    vowel_patterns: Vec<&'static str>,
+    /// Consonant patterns, longest first and then alphabetically
+    consonant_patterns: Vec<&'static str>,
+    /// Whether a 'y'/'z' after a consonant forms a ya-phala conjunct
+    ya_phala: bool,
+    /// Whole words yielded as `Foreign` tokens instead of `Word`
+    protected_terms: Vec<String>,
+    /// Whether protected terms match regardless of case
+    protected_ignore_case: bool,
+    /// How an apostrophe between two letters of a word is handled
+    apostrophe: ApostrophePolicy,
+    /// Whether only uppercase "OI" and "OU" are read as diphthongs
+    diphthong_case_sensitive: bool,
+    /// Whether unmapped ASCII letters are read by their fallback spelling
+    ascii_fallback: bool,
+    /// Most consonants joined in one conjunct without an explicit hasant
+    max_conjunct_length: usize,
 }
 
 impl Tokenizer {
     /// Create a new tokenizer with default configuration
     pub fn new() -> Self {
-        let mut special_sequences = HashMap::new();
-        let mut vowel_patterns = HashMap::new();
-        let mut consonant_patterns = HashMap::new();
+        let mut special_sequences = Map::new();
+        let mut vowel_patterns = Vec::new();
         
         // Get vowel patterns from the definitions
         let vowels_map = vowels();
-        println!("DEBUG: Available vowel patterns: {:?}", vowels_map.keys().collect::<Vec<_>>());
+        log::debug!("Available vowel patterns: {:?}", vowels_map.keys().collect::<Vec<_>>());
         
         for roman in vowels_map.keys() {
             // Mark only 'o' as a terminating vowel
             if *roman == "o" {
                 continue; // Skip adding to vowel_patterns, will add as terminator
             }
-            vowel_patterns.insert(roman.to_string(), true);
+            vowel_patterns.push(*roman);
         }
         
-        println!("DEBUG: Added vowel patterns: {:?}", vowel_patterns.keys().collect::<Vec<_>>());
+        log::debug!("Added vowel patterns: {:?}", vowel_patterns);
         
         // Add terminating vowel 'o' separately
         if vowels_map.contains_key("o") {
@@ -125,10 +240,7 @@ impl Tokenizer {
         }
         
         // Get consonant patterns from the definitions
-        let consonants_map = consonants();
-        for roman in consonants_map.keys() {
-            consonant_patterns.insert(roman.to_string(), true);
-        }
+        let mut consonant_patterns: Vec<&'static str> = consonants().keys().copied().collect();
         
         // Initialize special sequences
         // Reph (র্) is a special form
@@ -136,7 +248,7 @@ impl Tokenizer {
         
         // Hasant/Virama from diacritics
         let diacritics_map = diacritics();
-        if let Some(hasant_key) = diacritics_map.iter().find_map(|(k, v)| {
+        if let Some(hasant_key) = diacritics_map.entries().find_map(|(k, v)| {
             if *v == "্" { Some(k) } else { None }
         }) {
             special_sequences.insert(hasant_key.to_string(), PhoneticUnitType::ConsonantWithHasant);
@@ -156,194 +268,314 @@ impl Tokenizer {
             special_sequences.insert(roman.to_string(), PhoneticUnitType::SpecialForm);
         }
         
+        // Match longest first, with an alphabetical tie-break, so the result
+        // doesn't depend on map iteration order
+        let mut special_sequences: Vec<(String, PhoneticUnitType)> = special_sequences.into_iter().collect();
+        special_sequences.sort_by(|(a, _), (b, _)| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+        vowel_patterns.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+        consonant_patterns.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+        
         Tokenizer {
             special_sequences,
             vowel_patterns,
             consonant_patterns,
+            ya_phala: true,
+            protected_terms: Vec::new(),
+            protected_ignore_case: false,
+            apostrophe: ApostrophePolicy::default(),
+            diphthong_case_sensitive: true,
+            ascii_fallback: true,
+            max_conjunct_length: usize::MAX,
         }
     }
     
-    /// Tokenize input text into words and other tokens
-    pub fn tokenize_text(&self, text: &str) -> Vec<Token> {
-        let mut tokens = Vec::new();
-        let mut current_word = String::new();
-        let mut current_position = 0;
-        
-        // Helper function to add the current word as a token
-        let add_current_word = |word: &mut String, pos: usize, tokens: &mut Vec<Token>| {
-            if !word.is_empty() {
-                // Determine if the word is a number
-                let token_type = if word.chars().all(|c| c.is_numeric()) {
-                    TokenType::Number
-                } else {
-                    TokenType::Word
-                };
-                
-                tokens.push(Token {
-                    content: word.clone(),
-                    token_type,
-                    position: pos,
-                });
-                word.clear();
-            }
-        };
+    /// Enable or disable ya-phala (য-ফলা) formation
+    ///
+    /// Enabled by default. When disabled, a `y` or `z` after a consonant does
+    /// not join it in a conjunct, so "bidyaloy" keeps দ and য় apart. An
+    /// explicit hasant (`d,,y`) still forms the conjunct.
+    pub fn with_ya_phala(mut self, enabled: bool) -> Self {
+        self.ya_phala = enabled;
+        self
+    }
+    
+    /// Set the words yielded as `Foreign` tokens, to be kept as typed
+    ///
+    /// Only whole word tokens match, so a term inside a longer word or a
+    /// hyphenated compound is not protected.
+    pub fn with_protected_terms(mut self, terms: Vec<String>) -> Self {
+        self.protected_terms = terms;
+        self
+    }
+    
+    /// Whether any words are protected
+    #[cfg(feature = "std")]
+    pub(crate) fn has_protected_terms(&self) -> bool {
+        !self.protected_terms.is_empty()
+    }
+    
+    /// Match protected terms regardless of case
+    ///
+    /// Disabled by default, so "iPhone" doesn't protect "iphone".
+    pub fn with_protected_terms_ignore_case(mut self, enabled: bool) -> Self {
+        self.protected_ignore_case = enabled;
+        self
+    }
+    
+    /// Set how an apostrophe between two letters of a word is handled
+    ///
+    /// Defaults to [`ApostrophePolicy::SyllableBreak`], so an emphatic particle
+    /// stays joined to its word ("amar'i" is আমারই). An apostrophe at the start
+    /// or end of a word, such as a quotation mark, is always punctuation.
+    pub fn with_apostrophe_policy(mut self, policy: ApostrophePolicy) -> Self {
+        self.apostrophe = policy;
+        self
+    }
+    
+    /// Read only uppercase "OI" and "OU" as the diphthongs ঐ and ঔ
+    ///
+    /// Enabled by default, so "boi" is বই and "bOI" is বৈ. When disabled, "oi"
+    /// and "ou" in any case are diphthongs too, and a hiatus marker ("bo.i")
+    /// keeps the vowels apart.
+    pub fn with_diphthong_case_sensitivity(mut self, enabled: bool) -> Self {
+        self.diphthong_case_sensitive = enabled;
+        self
+    }
+    
+    /// Read ASCII letters without a mapping by their fallback spelling
+    ///
+    /// Enabled by default, as in Avro: `x` is ক্স, `q` is ক, a `w` that is
+    /// not a bo-fola is ও and uppercase letters with no meaning of their own
+    /// read as lowercase, so `Kh` is খ.
+    /// When disabled, these letters are `Unknown` units passed through as
+    /// typed. See [`ascii_fallback`](crate::definitions::ascii_fallback).
+    pub fn with_ascii_fallback(mut self, enabled: bool) -> Self {
+        self.ascii_fallback = enabled;
+        self
+    }
+    
+    /// Limit the number of consonants joined in one conjunct
+    ///
+    /// Unlimited by default. A run of more consonants is broken greedily from
+    /// the left: the first `max` form a cluster ending in an explicit hasant,
+    /// which is written visible (্ with a ZWNJ), and the next consonant starts
+    /// a new cluster. With a limit of 3, "kstrp" is ক্স্ত্‌ + র্প. Conjuncts
+    /// typed with an explicit hasant (`k,,t`) are not limited, and a limit
+    /// below 2 is treated as 2.
+    pub fn with_max_conjunct_length(mut self, max: usize) -> Self {
+        self.max_conjunct_length = max.max(2);
+        self
+    }
+    
+    /// Rewrite the letters of a word that no pattern matches by their fallback spelling
+    ///
+    /// Also returns, for each byte of the rewritten word and one past its end,
+    /// the byte offset in `word` it came from.
+    fn apply_ascii_fallback(&self, word: &str) -> (String, Vec<usize>) {
+        let mut rewritten = String::with_capacity(word.len());
+        let mut offsets = Vec::with_capacity(word.len() + 1);
+        let mut rest = word;
         
-        let mut i = 0;
-        while i < text.len() {
-            // Get the current character
-            let c = text[i..].chars().next().unwrap();
-            let char_len = c.len_utf8();
-            
-            // Special case: Check for diacritics that should attach to the previous word
-            if !current_word.is_empty() && (c == '^' || c == ':' || c == '`') {
-                // Special case for Khanda Ta (T``)
-                if c == '`' && i + 1 < text.len() && text.chars().nth(i + 1) == Some('`') {
-                    if current_word.ends_with('T') {
-                        // Add the `` to mark it as Khanda Ta
-                        current_word.push_str("``");
-                        i += 2; // Skip both backticks
-                        continue;
-                    }
-                }
-                
-                // Handle ^ (Chandrabindu) and : (Visarga) as part of the word
-                if c == '^' || c == ':' {
-                    current_word.push(c);
-                    i += char_len;
-                    continue;
-                }
-            }
-            
-            // Special case: Check for hasanta sequence (,,)
-            if c == ',' && i + 1 < text.len() && text.chars().nth(i + 1) == Some(',') {
-                // If we're in a word context and there's a consonant before this
-                if !current_word.is_empty() {
-                    // Add the sequence to the current word
-                    current_word.push_str(",,");
-                    i += 2; // Skip both commas
-                    continue;
-                } else {
-                    // If we're not in a word context, handle as regular punctuation
-                    add_current_word(&mut current_word, current_position, &mut tokens);
-                    
-                    // Add the first comma as punctuation
-                    tokens.push(Token {
-                        content: ",".to_string(),
-                        token_type: TokenType::Punctuation,
-                        position: i,
-                    });
-                    
-                    i += 1; // Move to the next comma
-                    current_position = i;
-                    continue;
-                }
-            }
+        while let Some(c) = rest.chars().next() {
+            // The longest pattern starting here is kept, so "NG" keeps its 'G'
+            let known = self.vowel_patterns.iter().chain(&self.consonant_patterns).copied()
+                .chain(self.special_sequences.iter().map(|(sequence, _)| sequence.as_str()))
+                .filter(|pattern| rest.starts_with(pattern))
+                .map(str::len)
+                .max();
             
-            if c.is_whitespace() {
-                // Add the current word if any
-                add_current_word(&mut current_word, current_position, &mut tokens);
-                
-                // Add the whitespace as a token
-                    tokens.push(Token {
-                    content: c.to_string(),
-                    token_type: TokenType::Whitespace,
-                    position: i,
-                });
-                
-                current_position = i + char_len;
-            } else if c.is_ascii_punctuation() {
-                // Add the current word if any
-                add_current_word(&mut current_word, current_position, &mut tokens);
-                
-                // Add the punctuation as a token
-                    tokens.push(Token {
-                    content: c.to_string(),
-                    token_type: TokenType::Punctuation,
-                    position: i,
-                });
-                
-                current_position = i + char_len;
-            } else if !c.is_alphanumeric() && !current_word.is_empty() {
-                // Special symbol - add the current word if any
-                add_current_word(&mut current_word, current_position, &mut tokens);
-                
-                // Add the symbol as a token
-                    tokens.push(Token {
-                    content: c.to_string(),
-                    token_type: TokenType::Symbol,
-                    position: i,
-                });
-                
-                current_position = i + char_len;
+            let len = match (known, ascii_fallback().get(&c)) {
+                (Some(len), _) => {
+                    rewritten.push_str(&rest[..len]);
+                    len
+                },
+                // After a consonant 'w' is the bo-fola, and anywhere else it is ও
+                (None, _) if matches!(c, 'w' | 'W') => {
+                    let after_consonant = rewritten.chars().last()
+                        .is_some_and(|prev| prev.is_ascii_alphabetic() && !"aeiouAEIOU".contains(prev));
+                    rewritten.push(if after_consonant { 'w' } else { 'O' });
+                    1
+                },
+                (None, Some(fallback)) => {
+                    rewritten.push_str(fallback);
+                    c.len_utf8()
+                },
+                (None, None) => {
+                    rewritten.push(c);
+                    c.len_utf8()
+                },
+            };
+            let offset = word.len() - rest.len();
+            offsets.resize(rewritten.len(), offset);
+            rest = &rest[len..];
+        }
+        offsets.push(word.len());
+        
+        (rewritten, offsets)
+    }
+    
+    /// Whether the text starts with one of the multi-letter vowels
+    fn starts_with_multi_vowel(&self, text: &str, vowel: &str) -> bool {
+        text.starts_with(vowel) ||
+            (!self.diphthong_case_sensitive && vowel.starts_with('O') &&
+             text.get(..vowel.len()).is_some_and(|start| start.eq_ignore_ascii_case(vowel)))
+    }
+    
+    /// Whether a word is one of the protected terms
+    fn is_protected(&self, word: &str) -> bool {
+        self.protected_terms.iter().any(|term| {
+            if self.protected_ignore_case {
+                term.chars().flat_map(char::to_lowercase).eq(word.chars().flat_map(char::to_lowercase))
             } else {
-                // If we have an empty current word, update the position
-                if current_word.is_empty() {
-                    current_position = i;
-                }
-                // Add the character to the current word
-                current_word.push(c);
+                term == word
             }
-            
-            i += char_len;
+        })
+    }
+    
+    /// Recognize an additional special form, matched as one `SpecialForm` unit
+    ///
+    /// Special forms are matched before consonants, longest first, so the
+    /// sequence is never split into a conjunct. An empty sequence, or one
+    /// with anything other than letters, is ignored.
+    pub fn register_special(&mut self, sequence: &str) {
+        if !is_special_sequence(sequence) {
+            log::warn!("Ignoring special form {:?}: only letters can be registered", sequence);
+            return;
+        }
+        if self.special_sequences.iter().any(|(existing, _)| existing == sequence) {
+            return;
         }
         
-        // Add any remaining word
-        add_current_word(&mut current_word, current_position, &mut tokens);
-        
-        tokens
+        self.special_sequences.push((sequence.to_string(), PhoneticUnitType::SpecialForm));
+        self.special_sequences.sort_by(|(a, _), (b, _)| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+    }
+    
+    /// Vowel patterns recognized by the tokenizer, sorted alphabetically
+    ///
+    /// The terminating vowel `o` is a special sequence and not listed here.
+    pub fn vowel_patterns(&self) -> Vec<&str> {
+        let mut patterns = self.vowel_patterns.clone();
+        patterns.sort_unstable();
+        patterns
+    }
+    
+    /// Consonant patterns recognized by the tokenizer, sorted alphabetically
+    pub fn consonant_patterns(&self) -> Vec<&str> {
+        let mut patterns = self.consonant_patterns.clone();
+        patterns.sort_unstable();
+        patterns
+    }
+    
+    /// Special sequences recognized by the tokenizer with the unit type they produce,
+    /// sorted alphabetically by sequence
+    pub fn special_sequences(&self) -> Vec<(&str, &PhoneticUnitType)> {
+        let mut sequences: Vec<(&str, &PhoneticUnitType)> = self.special_sequences.iter()
+            .map(|(sequence, unit_type)| (sequence.as_str(), unit_type))
+            .collect();
+        sequences.sort_unstable_by_key(|(sequence, _)| *sequence);
+        sequences
+    }
+    
+    /// Tokenize input text into words and other tokens
+    pub fn tokenize_text(&self, text: &str) -> Vec<Token> {
+        self.token_iter(text).collect()
+    }
+    
+    /// Lazily tokenize input text, yielding the same tokens as [`Self::tokenize_text`]
+    ///
+    /// Only the word being built is buffered, so memory use doesn't grow with the text.
+    pub fn token_iter<'a>(&'a self, text: &'a str) -> TokenIter<'a> {
+        TokenIter {
+            tokenizer: self,
+            text,
+            i: 0,
+            current_word: String::new(),
+            current_position: 0,
+            pending: None,
+        }
     }
     
     /// Tokenize a word into phonetic units for Bengali transliteration
     pub fn tokenize_word(&self, word: &str) -> Vec<PhoneticUnit> {
         let mut units = Vec::new();
         
-        println!("DEBUG: Tokenizing word: {}", word);
+        log::debug!("Tokenizing word: {}", word);
         
         // Process the word character by character
         let mut _i = 0;
         
-        // Pre-process special sequences
-        let mut processed_word = word.to_string();
+        // Pre-process special sequences, keeping the offsets of the letters
+        // the fallback rewrote so that unit positions refer to `word`
+        let (mut processed_word, offsets) = if self.ascii_fallback {
+            let (rewritten, offsets) = self.apply_ascii_fallback(word);
+            let rewrote = rewritten != word;
+            (rewritten, rewrote.then_some(offsets))
+        } else {
+            (word.to_string(), None)
+        };
         
-        // Check for chandrabindu (^) and visarga (:) at the end
+        // Check for chandrabindu (^) at the end. A visarga (:) is not stripped:
+        // wherever it is in the word, it is matched as its own unit below.
         let has_chandrabindu = processed_word.ends_with('^');
-        let has_visarga = processed_word.ends_with(':');
         
-        // Remove the diacritics for processing
+        // Remove the diacritic for processing
         if has_chandrabindu {
             processed_word.pop();  // Remove the chandrabindu
-        } else if has_visarga {
-            processed_word.pop();  // Remove the visarga
         }
         
-        // Special case for standalone diacritics
-        if processed_word.is_empty() && (has_chandrabindu || has_visarga) {
-            // Handle standalone diacritics directly
-            if has_chandrabindu {
-                units.push(PhoneticUnit {
-                    text: "^".to_string(),
-                    unit_type: PhoneticUnitType::SpecialForm,
-                    position: 0,
-                });
-            } else if has_visarga {
-                units.push(PhoneticUnit {
-                    text: ":".to_string(),
-                    unit_type: PhoneticUnitType::SpecialForm,
-                    position: 0,
-                });
-            }
+        // Special case for a standalone chandrabindu
+        if processed_word.is_empty() && has_chandrabindu {
+            units.push(PhoneticUnit {
+                text: "^".to_string(),
+                unit_type: PhoneticUnitType::SpecialForm,
+                position: 0,
+            });
             return units;
         }
         
         // Process the base word without diacritics
         while _i < processed_word.len() {
+            // Consume a hiatus marker so the vowels on either side are matched separately
+            if processed_word[_i..].starts_with('.') &&
+               self.is_hiatus(&processed_word[.._i], &processed_word[_i + 1..]) {
+                _i += 1;
+                continue;
+            }
+            
+            // A backslash between two r's ("r\r") escapes the reph: it is kept
+            // as its own unit, so the ras on either side are written separately
+            if processed_word[_i..].starts_with("\\r") && processed_word[.._i].ends_with('r') {
+                units.push(PhoneticUnit {
+                    text: "\\".to_string(),
+                    unit_type: PhoneticUnitType::SpecialForm,
+                    position: _i,
+                });
+                _i += 1;
+                continue;
+            }
+            
+            // An apostrophe kept inside the word (see `with_apostrophe_policy`) is
+            // skipped, or kept as a unit that separates the letters on either side
+            if processed_word[_i..].starts_with('\'') {
+                if self.apostrophe == ApostrophePolicy::SyllableBreak {
+                    units.push(PhoneticUnit {
+                        text: "'".to_string(),
+                        unit_type: PhoneticUnitType::SpecialForm,
+                        position: _i,
+                    });
+                }
+                _i += 1;
+                continue;
+            }
+            
             // First check for multi-letter vowels like "rri", "OI", "OU"
+            // (a hiatus marker, "O.I", was consumed above and prevents the match)
             let mut matched_multi_vowel = false;
             let multi_letter_vowels = ["rri", "OI", "OU"];
             
             for vowel in &multi_letter_vowels {
-                if _i + vowel.len() <= processed_word.len() && &processed_word[_i.._i+vowel.len()] == *vowel {
-                    println!("DEBUG: Found multi-letter vowel: {}", vowel);
+                if self.starts_with_multi_vowel(&processed_word[_i..], vowel) {
+                    log::debug!("Found multi-letter vowel: {}", vowel);
                     units.push(PhoneticUnit {
                         text: vowel.to_string(),
                         unit_type: PhoneticUnitType::Vowel,
@@ -362,8 +594,11 @@ impl Tokenizer {
             // Try to match special sequences first
             let mut matched = false;
             
-            // Try to match "ng" specifically before other sequences
-            if _i + 2 <= processed_word.len() && &processed_word[_i.._i+2] == "ng" {
+            // Try to match "ng" (anusvara) specifically before other sequences.
+            // Matching is case-sensitive: the capital nasals "NG" (ঞ), "Ng" (ঙ)
+            // and "N" (ণ) never match here and are consonant patterns, which
+            // are tried longest first, so "NG" and "Ng" win over "N".
+            if processed_word[_i..].starts_with("ng") {
                 units.push(PhoneticUnit {
                     text: "ng".to_string(),
                     unit_type: PhoneticUnitType::SpecialForm,
@@ -375,11 +610,16 @@ impl Tokenizer {
             
             for (sequence, unit_type) in &self.special_sequences {
                 // Skip "rr" if the next character is "i" (part of "rri")
-                if sequence == "rr" && _i + 3 <= processed_word.len() && &processed_word[_i.._i+3] == "rri" {
+                if sequence == "rr" && processed_word[_i..].starts_with("rri") {
+                    continue;
+                }
+                
+                // Skip "o" if it starts the doubled vowel "oo"
+                if sequence == "o" && processed_word[_i..].starts_with("oo") {
                     continue;
                 }
                 
-                if _i + sequence.len() <= processed_word.len() && &processed_word[_i.._i+sequence.len()] == sequence {
+                if processed_word[_i..].starts_with(sequence.as_str()) {
                     // Ensure all special forms are treated as SpecialForm, even T``
                     let final_unit_type = if sequence == "T``" {
                         PhoneticUnitType::SpecialForm
@@ -404,13 +644,11 @@ impl Tokenizer {
                 
             // Try to match consonant patterns (longer patterns first)
             let mut matched_consonant = false;
-            let mut consonant_patterns: Vec<_> = self.consonant_patterns.keys().collect();
-            consonant_patterns.sort_by(|a, b| b.len().cmp(&a.len())); // Sort by length, descending
             
-            for pattern in consonant_patterns {
-                if _i + pattern.len() <= processed_word.len() && &processed_word[_i.._i+pattern.len()] == pattern {
+            for pattern in &self.consonant_patterns {
+                if processed_word[_i..].starts_with(pattern) {
                     units.push(PhoneticUnit {
-                        text: pattern.clone(),
+                        text: pattern.to_string(),
                         unit_type: PhoneticUnitType::Consonant,
                         position: _i,
                     });
@@ -427,26 +665,11 @@ impl Tokenizer {
             // Try to match vowel patterns (longer patterns first)
             let mut matched_vowel = false;
             
-            // Remove the special case for multi-letter vowels and instead
-            // ensure all vowel patterns from vowels() are used, sorted properly
-            let mut vowel_patterns: Vec<_> = self.vowel_patterns.keys().collect();
-            vowel_patterns.sort_by(|a, b| b.len().cmp(&a.len())); // Sort by length, descending
-            
-            if word == "krri" && _i == 1 {
-                println!("DEBUG: Checking for vowels at position {} in '{}', remaining: '{}'", 
-                         _i, word, &processed_word[_i..]);
-                for pattern in &vowel_patterns {
-                    if _i + pattern.len() <= processed_word.len() {
-                        println!("DEBUG: Checking pattern '{}' against '{}'", 
-                                 pattern, &processed_word[_i.._i+pattern.len()]);
-                    }
-                }
-            }
-            
-            for pattern in &vowel_patterns {
-                if _i + pattern.len() <= processed_word.len() && &processed_word[_i.._i+pattern.len()] == *pattern {
+            // All vowel patterns from vowels() are used, sorted when the tokenizer is built
+            for pattern in &self.vowel_patterns {
+                if processed_word[_i..].starts_with(pattern) {
                     units.push(PhoneticUnit {
-                        text: (*pattern).clone(),
+                        text: pattern.to_string(),
                         unit_type: PhoneticUnitType::Vowel,
                         position: _i,
                     });
@@ -460,6 +683,20 @@ impl Tokenizer {
                 continue;
             }
             
+            // 'w' directly after a consonant is a bo-fola (ব-ফলা), e.g. "biSw" -> বিশ্ব.
+            // Anywhere else the ASCII fallback reads it as ও; without the
+            // fallback it has no mapping and falls through to Unknown below.
+            if processed_word[_i..].starts_with('w') &&
+               units.last().is_some_and(|u: &PhoneticUnit| u.unit_type == PhoneticUnitType::Consonant) {
+                units.push(PhoneticUnit {
+                    text: "w".to_string(),
+                    unit_type: PhoneticUnitType::BoFola,
+                    position: _i,
+                });
+                _i += 1;
+                continue;
+            }
+            
             // If no pattern matched, treat as unknown and advance by one character
             if _i < processed_word.len() {
                 // Find the length of one UTF-8 character
@@ -477,6 +714,12 @@ impl Tokenizer {
         // Post-processing to identify conjuncts and other complex forms
         self.identify_complex_forms(&mut units);
         
+        if let Some(offsets) = offsets {
+            for unit in &mut units {
+                unit.position = offsets[unit.position];
+            }
+        }
+        
         // Reapply the diacritics if present
         if !units.is_empty() {
             if has_chandrabindu {
@@ -511,134 +754,127 @@ impl Tokenizer {
                         // If it doesn't fit any of the above, just keep the original type
                     }
                 }
-            } else if has_visarga {
-                // For visarga, we now add it as a separate unit instead of combining
-                // Get the position for the new visarga unit
-                let position = {
-                    let last = units.last().unwrap();
-                    last.position + last.text.len()
-                };
-                
-                // Now add the visarga as a separate unit
-                units.push(PhoneticUnit {
-                    text: ":".to_string(),
-                    unit_type: PhoneticUnitType::SpecialForm,
-                    position,
-                });
             }
         }
         
         units
     }
     
+    /// Whether a dot between `before` and `after` is a vowel hiatus marker
+    ///
+    /// The marker is only recognized where the vowels on either side would
+    /// otherwise be read together: a diphthong ("kO.I"), a doubled vowel
+    /// ("ki.i") and a word-final vowel taking the য় glide ("ki.a"). Anywhere
+    /// else, as in "ami.amar" or "ami. tumi", the dot is a dari.
+    fn is_hiatus(&self, before: &str, after: &str) -> bool {
+        // The terminating 'o' is a special sequence rather than a vowel pattern
+        if !self.vowel_patterns.iter().copied().chain(["o"]).any(|vowel| before.ends_with(vowel)) {
+            return false;
+        }
+        
+        // The letters on either side of the dot, read as one
+        let pair: String = before.chars().next_back().into_iter().chain(after.chars().next()).collect();
+        let diphthong = ["OI", "OU"].iter().any(|vowel| self.starts_with_multi_vowel(&pair, vowel));
+        let doubled = pair.len() == 2 && pair[..1] == pair[1..] && self.vowel_patterns.contains(&pair.as_str());
+        let glide = vowel_vowel_combinations().keys().any(|vowel| {
+            after.strip_prefix(vowel).is_some_and(|rest| !rest.starts_with(|c: char| c.is_ascii_alphabetic()))
+        });
+        
+        diphthong || doubled || glide
+    }
+    
+    /// Whether a unit can take another consonant to form (or extend) a conjunct
+    ///
+    /// Only bare consonant clusters qualify. Once a vowel has attached, the
+    /// cluster is closed; see [`Self::with_vowel`].
+    fn extends_conjunct(unit_type: &PhoneticUnitType) -> bool {
+        matches!(unit_type, PhoneticUnitType::Consonant | PhoneticUnitType::Conjunct)
+    }
+    
+    /// The unit type formed when a vowel attaches to a consonant cluster
+    ///
+    /// This is the single place that implements the compound stopper rule: a
+    /// terminating 'o' attaches like any other vowel, closing the cluster so the
+    /// next consonant starts a new one. Hence "kk" becomes ক্ক but "kok" becomes কক.
+    fn with_vowel(base: &PhoneticUnitType, vowel: &PhoneticUnitType) -> Option<PhoneticUnitType> {
+        use PhoneticUnitType::*;
+        
+        match (base, vowel) {
+            (Consonant, Vowel) => Some(ConsonantWithVowel),
+            (Consonant, TerminatingVowel) => Some(ConsonantWithTerminator),
+            (Conjunct, Vowel) => Some(ConjunctWithVowel),
+            (Conjunct, TerminatingVowel) => Some(ConjunctWithTerminator),
+            (RephOverConsonant, Vowel) => Some(RephOverConsonantWithVowel),
+            (RephOverConsonant, TerminatingVowel) => Some(RephOverConsonantWithTerminator),
+            _ => None,
+        }
+    }
+    
+    /// The unit formed by a standalone "rr" at the start of `window`, with the
+    /// number of units it replaces
+    ///
+    /// Up to two units after the "rr" are considered, so a reph, its consonant
+    /// and the consonant's vowel merge in a single step:
+    /// - "rr" + "i" is the vocalic R vowel (ঋ)
+    /// - "rr" + consonant (+ vowel) is a reph over the consonant
+    /// - "rr" + any other vowel is র with the vowel sign, since a reph needs a
+    ///   consonant to sit on ("rrekha" is রেখা)
+    fn reph_form(window: &[PhoneticUnit]) -> Option<(PhoneticUnit, usize)> {
+        use PhoneticUnitType::*;
+        
+        let (rr, next) = match window {
+            [rr, next, ..] if rr.text == "rr" && rr.unit_type == SpecialForm => (rr, next),
+            _ => return None,
+        };
+        let merged = |text: String, unit_type: PhoneticUnitType, consumed: usize| {
+            Some((PhoneticUnit { text, unit_type, position: rr.position }, consumed))
+        };
+        
+        match next.unit_type {
+            Vowel if next.text == "i" => merged("rri".to_string(), Vowel, 2),
+            Vowel | TerminatingVowel => {
+                let unit_type = Self::with_vowel(&Consonant, &next.unit_type)?;
+                merged(format!("r{}", next.text), unit_type, 2)
+            },
+            Consonant => {
+                // The consonant's own vowel, if any, attaches in the same step
+                let vowel = window.get(2)
+                    .and_then(|vowel| Some((vowel, Self::with_vowel(&RephOverConsonant, &vowel.unit_type)?)));
+                match vowel {
+                    Some((vowel, unit_type)) => merged(format!("rr{}{}", next.text, vowel.text), unit_type, 3),
+                    None => merged(format!("rr{}", next.text), RephOverConsonant, 2),
+                }
+            },
+            ConsonantWithVowel => merged(format!("rr{}", next.text), RephOverConsonantWithVowel, 2),
+            ConsonantWithTerminator => merged(format!("rr{}", next.text), RephOverConsonantWithTerminator, 2),
+            _ => None,
+        }
+    }
+    
     /// Identify complex phonetic forms like conjuncts and consonants with vowel modifiers
     fn identify_complex_forms(&self, units: &mut Vec<PhoneticUnit>) {
         let mut _i = 0;
         
-        println!("DEBUG: After initial tokenization, {} units", units.len());
+        log::debug!("After initial tokenization, {} units", units.len());
         for unit in units.iter() {
-            println!("DEBUG: Unit '{}' type: {:?}", unit.text, unit.unit_type);
+            log::trace!("Unit '{}' type: {:?}", unit.text, unit.unit_type);
         }
         
-        // First pass: Handle special "rr" cases
-        // - "rri" as vocalic R vowel
-        // - "rr" + consonant as reph
+        // First pass: Merge each standalone "rr" with the units it governs
         _i = 0;
         while _i < units.len() {
-            // Handle "rr" + "i" as vocalic R vowel
-            if _i + 1 < units.len() && 
-               units[_i].text == "rr" && 
-               units[_i].unit_type == PhoneticUnitType::SpecialForm && 
-               units[_i+1].text == "i" && 
-               units[_i+1].unit_type == PhoneticUnitType::Vowel {
-                
-                let vowel_text = format!("rri");
-                let _position = units[_i].position;
-                
-                // Replace with a single vowel unit
-                units[_i] = PhoneticUnit {
-                    text: vowel_text,
-                    unit_type: PhoneticUnitType::Vowel, // Vocalic R is a vowel
-                    position: _position,
-                };
-                
-                // Remove the "i" unit
-                units.remove(_i+1);
-                continue;
-            }
-            
-            // Handle "rr" + consonant as reph over consonant
-            if _i + 1 < units.len() && 
-               units[_i].text == "rr" && 
-               units[_i].unit_type == PhoneticUnitType::SpecialForm && 
-               units[_i+1].unit_type == PhoneticUnitType::Consonant {
-                
-                let reph_text = format!("rr{}", units[_i+1].text);
-                let _position = units[_i].position;
-                
-                // Replace with a reph over consonant unit
-                units[_i] = PhoneticUnit {
-                    text: reph_text,
-                    unit_type: PhoneticUnitType::RephOverConsonant,
-                    position: _position,
-                };
-                
-                // Remove the consonant unit
-                units.remove(_i+1);
-                continue;
+            if let Some((unit, consumed)) = Self::reph_form(&units[_i..]) {
+                units.splice(_i.._i + consumed, core::iter::once(unit));
             }
-            
-            // Handle "rr" + consonantWithVowel as reph over consonant with vowel
-            if _i + 1 < units.len() && 
-               units[_i].text == "rr" && 
-               units[_i].unit_type == PhoneticUnitType::SpecialForm && 
-               units[_i+1].unit_type == PhoneticUnitType::ConsonantWithVowel {
-                
-                let reph_text = format!("rr{}", units[_i+1].text);
-                let _position = units[_i].position;
-                
-                // Replace with a reph over consonant with vowel unit
-                units[_i] = PhoneticUnit {
-                    text: reph_text,
-                    unit_type: PhoneticUnitType::RephOverConsonantWithVowel,
-                    position: _position,
-                };
-                
-                // Remove the consonant with vowel unit
-                units.remove(_i+1);
-                continue;
-            }
-            
-            // Handle "rr" + consonantWithTerminator as reph over consonant with terminator
-            if _i + 1 < units.len() && 
-               units[_i].text == "rr" && 
-               units[_i].unit_type == PhoneticUnitType::SpecialForm && 
-               units[_i+1].unit_type == PhoneticUnitType::ConsonantWithTerminator {
-                
-                let reph_text = format!("rr{}", units[_i+1].text);
-                let _position = units[_i].position;
-                
-                // Replace with a reph over consonant with terminator unit
-                units[_i] = PhoneticUnit {
-                    text: reph_text,
-                    unit_type: PhoneticUnitType::RephOverConsonantWithTerminator,
-                    position: _position,
-                };
-                
-                // Remove the consonant with terminator unit
-                units.remove(_i+1);
-                continue;
-            }
-            
-                _i += 1;
+            _i += 1;
         }
         
-        // Second pass: Form basic units and conjuncts
+        // Second pass: Form conjuncts and attach vowels
         _i = 0;
         while _i < units.len() {
-            // Identify consonant + hasant (,,) + consonant as an explicit conjunct
+            // Identify cluster + hasant (,,) + consonant as an explicit conjunct
             if _i + 2 < units.len() && 
-               units[_i].unit_type == PhoneticUnitType::Consonant &&
+               Self::extends_conjunct(&units[_i].unit_type) &&
                units[_i+1].unit_type == PhoneticUnitType::ConsonantWithHasant &&
                units[_i+2].unit_type == PhoneticUnitType::Consonant {
                 
@@ -646,6 +882,7 @@ impl Tokenizer {
                     units[_i].text, units[_i+1].text, units[_i+2].text);
                 
                 let _position = units[_i].position;
+                log::debug!("Formed explicit conjunct '{}'", conjunct_text);
                 
                 // Replace the three units with a single conjunct unit
                 units[_i] = PhoneticUnit {
@@ -663,60 +900,44 @@ impl Tokenizer {
                 continue;
             }
             
-            // Handle consonant + vocalic R vowel as consonant with vowel
-            if _i + 1 < units.len() && 
-               units[_i].unit_type == PhoneticUnitType::Consonant &&
-               units[_i+1].text == "rri" && 
-               units[_i+1].unit_type == PhoneticUnitType::Vowel {
-                
-                println!("DEBUG: Found consonant + vocalic R vowel: {} + {}", units[_i].text, units[_i+1].text);
-                
-                let combined_text = format!("{}{}", units[_i].text, units[_i+1].text);
-                let _position = units[_i].position;
-                
-                // Replace with a single consonant with vowel unit
-                units[_i] = PhoneticUnit {
-                    text: combined_text,
-                    unit_type: PhoneticUnitType::ConsonantWithVowel,
-                    position: _position,
-                };
-                
-                // Remove the vowel unit
-                units.remove(_i+1);
-                continue;
-            }
-            
-            // Identify consonant + vowel as a consonant with vowel unit (general case)
-            if _i + 1 < units.len() && 
-               units[_i].unit_type == PhoneticUnitType::Consonant &&
-               units[_i+1].unit_type == PhoneticUnitType::Vowel {
-                
-                let combined_text = format!("{}{}", units[_i].text, units[_i+1].text);
-                let _position = units[_i].position;
-                
-                // Replace with a single consonant with vowel unit
-                units[_i] = PhoneticUnit {
-                    text: combined_text,
-                    unit_type: PhoneticUnitType::ConsonantWithVowel,
-                    position: _position,
-                };
-                
-                // Remove the vowel unit
-                units.remove(_i+1);
-                
-                // Don't increment _i since we want to check if the new unit
-                // is part of a larger complex form
-                continue;
+            // Attach a vowel (including the terminating 'o') to the preceding cluster
+            if _i + 1 < units.len() {
+                if let Some(unit_type) = Self::with_vowel(&units[_i].unit_type, &units[_i+1].unit_type) {
+                    let combined_text = format!("{}{}", units[_i].text, units[_i+1].text);
+                    let _position = units[_i].position;
+                    
+                    units[_i] = PhoneticUnit {
+                        text: combined_text,
+                        unit_type,
+                        position: _position,
+                    };
+                    
+                    // Remove the vowel unit
+                    units.remove(_i+1);
+                    continue;
+                }
             }
             
             // Form conjuncts from consecutive consonants (without explicit hasant)
             if _i + 1 < units.len() && 
-               units[_i].unit_type == PhoneticUnitType::Consonant &&
-               units[_i+1].unit_type == PhoneticUnitType::Consonant {
+               Self::extends_conjunct(&units[_i].unit_type) &&
+               units[_i+1].unit_type == PhoneticUnitType::Consonant &&
+               (self.ya_phala || !matches!(units[_i+1].text.as_str(), "y" | "z")) {
+                
+                // A full cluster ends with a hasant, and the consonant starts the next one
+                if units[_i].unit_type == PhoneticUnitType::Conjunct &&
+                   units[_i].text.split(",,").filter(|part| !part.is_empty()).count() >= self.max_conjunct_length {
+                    if !units[_i].text.ends_with(",,") {
+                        units[_i].text.push_str(",,");
+                    }
+                    _i += 1;
+                    continue;
+                }
                 
                 // Form an implicit conjunct by adding virtual hasant
                 let conjunct_text = format!("{}{}{}", units[_i].text, ",,", units[_i+1].text);
                 let _position = units[_i].position;
+                log::debug!("Formed conjunct '{}'", conjunct_text);
                 
                 // Replace with a single conjunct unit
                 units[_i] = PhoneticUnit {
@@ -733,63 +954,6 @@ impl Tokenizer {
                 continue;
             }
             
-            // Form conjunct with vowel: consonant + consonantWithVowel
-            if _i + 1 < units.len() && 
-               units[_i].unit_type == PhoneticUnitType::Consonant &&
-               (units[_i+1].unit_type == PhoneticUnitType::ConsonantWithVowel ||
-                units[_i+1].unit_type == PhoneticUnitType::ConsonantWithTerminator) {
-                
-                // Separate the consonant and vowel parts from the second unit
-                let cons2 = &units[_i+1].text[0..1]; // First character is the consonant
-                let vowel_part = &units[_i+1].text[1..]; // Rest is the vowel
-                
-                // Form conjunct with vowel
-                let conjunct_text = format!("{}{}{}{}", units[_i].text, ",,", cons2, vowel_part);
-                let _position = units[_i].position;
-                
-                // Choose the right unit type based on whether it has a terminator vowel or regular vowel
-                let unit_type = if units[_i+1].unit_type == PhoneticUnitType::ConsonantWithTerminator {
-                    PhoneticUnitType::ConjunctWithTerminator
-            } else {
-                    PhoneticUnitType::ConjunctWithVowel
-                };
-                
-                // Replace with a single conjunct with vowel unit
-                units[_i] = PhoneticUnit {
-                    text: conjunct_text,
-                    unit_type,
-                    position: _position,
-                };
-                
-                // Remove the second unit
-                units.remove(_i+1);
-                
-                continue;
-            }
-            
-            // Identify consonant + terminating vowel as a consonant with terminator
-            if _i + 1 < units.len() && 
-               units[_i].unit_type == PhoneticUnitType::Consonant &&
-               units[_i+1].unit_type == PhoneticUnitType::TerminatingVowel {
-                
-                let combined_text = format!("{}{}", units[_i].text, units[_i+1].text);
-                let _position = units[_i].position;
-                
-                // Replace with a single consonant with terminator unit
-                units[_i] = PhoneticUnit {
-                    text: combined_text,
-                    unit_type: PhoneticUnitType::ConsonantWithTerminator,
-                    position: _position,
-                };
-                
-                // Remove the vowel unit
-                units.remove(_i+1);
-                
-                // Don't increment _i since we want to check if the new unit
-                // is part of a larger complex form
-                continue;
-            }
-            
             // Handle Chandrabindu (^) applying to the previous unit - ONLY THIS GETS SPECIAL TREATMENT
             if _i > 0 && _i < units.len() && 
                units[_i].text == "^" && 
@@ -822,7 +986,10 @@ impl Tokenizer {
                 continue;
             }
             
-            // For Visarga (:), "ng", "T``", and other diacritics - treat as separate units
+            // A visarga (:) stays its own unit right after the unit it follows, in
+            // the middle of a word as at the end, so ঃ is written after that
+            // unit and a consonant after it never joins a conjunct across it.
+            // "ng" and "T``" are kept as separate units too.
             if (units[_i].text == ":" || units[_i].text == "ng" || units[_i].text == "T``") && 
                units[_i].unit_type == PhoneticUnitType::SpecialForm {
                 // Keep as separate units - do nothing special
@@ -833,89 +1000,7 @@ impl Tokenizer {
             _i += 1;
         }
         
-        // Third pass: Handle vowels with conjuncts and reph
-        _i = 0;
-        while _i < units.len() - 1 {
-            // Conjunct + Vowel -> ConjunctWithVowel
-            if units[_i].unit_type == PhoneticUnitType::Conjunct && 
-               units[_i+1].unit_type == PhoneticUnitType::Vowel {
-                
-                let combined_text = format!("{}{}", units[_i].text, units[_i+1].text);
-                let _position = units[_i].position;
-                
-                // Replace with a single conjunct with vowel unit
-                units[_i] = PhoneticUnit {
-                    text: combined_text,
-                    unit_type: PhoneticUnitType::ConjunctWithVowel,
-                    position: _position,
-                };
-                
-                // Remove the vowel unit
-                units.remove(_i+1);
-                continue;
-            }
-            
-            // Conjunct + TerminatingVowel -> ConjunctWithTerminator
-            if units[_i].unit_type == PhoneticUnitType::Conjunct && 
-               units[_i+1].unit_type == PhoneticUnitType::TerminatingVowel {
-                
-                let combined_text = format!("{}{}", units[_i].text, units[_i+1].text);
-                let _position = units[_i].position;
-                
-                // Replace with a single conjunct with terminator unit
-                units[_i] = PhoneticUnit {
-                    text: combined_text,
-                    unit_type: PhoneticUnitType::ConjunctWithTerminator,
-                    position: _position,
-                };
-                
-                // Remove the vowel unit
-                units.remove(_i+1);
-                continue;
-            }
-            
-            // RephOverConsonant + Vowel -> RephOverConsonantWithVowel
-            if units[_i].unit_type == PhoneticUnitType::RephOverConsonant && 
-               units[_i+1].unit_type == PhoneticUnitType::Vowel {
-                
-                let combined_text = format!("{}{}", units[_i].text, units[_i+1].text);
-                let _position = units[_i].position;
-                
-                // Replace with a single reph over consonant with vowel unit
-                units[_i] = PhoneticUnit {
-                    text: combined_text,
-                    unit_type: PhoneticUnitType::RephOverConsonantWithVowel,
-                    position: _position,
-                };
-                
-                // Remove the vowel unit
-                units.remove(_i+1);
-                continue;
-            }
-            
-            // RephOverConsonant + TerminatingVowel -> RephOverConsonantWithTerminator
-            if units[_i].unit_type == PhoneticUnitType::RephOverConsonant && 
-               units[_i+1].unit_type == PhoneticUnitType::TerminatingVowel {
-                
-                let combined_text = format!("{}{}", units[_i].text, units[_i+1].text);
-                let _position = units[_i].position;
-                
-                // Replace with a single reph over consonant with terminator unit
-                units[_i] = PhoneticUnit {
-                    text: combined_text,
-                    unit_type: PhoneticUnitType::RephOverConsonantWithTerminator,
-                    position: _position,
-                };
-                
-                // Remove the vowel unit
-                units.remove(_i+1);
-                continue;
-            }
-            
-            _i += 1;
-        }
-        
-        // Fourth pass: Handle diacritics and special forms
+        // Third pass: Handle diacritics and special forms
         _i = 0;
         while _i < units.len() {
             // For Chandrabindu (^) - if a unit text ends with ^, update its type
@@ -953,9 +1038,225 @@ impl Tokenizer {
             
             _i += 1;
         }
+        
+        // Final step: A word-final hasant joins the consonant or conjunct before it,
+        // marking it as pronounced without a vowel ("bak,," is বাক্)
+        if let [.., base, hasant] = units.as_slice() {
+            if hasant.unit_type == PhoneticUnitType::ConsonantWithHasant && Self::extends_conjunct(&base.unit_type) {
+                let unit_type = match base.unit_type {
+                    PhoneticUnitType::Consonant => PhoneticUnitType::ConsonantWithHasant,
+                    _ => PhoneticUnitType::Conjunct,
+                };
+                let text = format!("{}{}", base.text, hasant.text);
+                let position = base.position;
+                
+                units.pop();
+                if let Some(last) = units.last_mut() {
+                    *last = PhoneticUnit { text, unit_type, position };
+                }
+            }
+        }
+    }
+}
+
+/// Iterator over the tokens of a text, created by [`Tokenizer::token_iter`]
+pub struct TokenIter<'a> {
+    tokenizer: &'a Tokenizer,
+    text: &'a str,
+    /// Byte offset of the next character to read
+    i: usize,
+    current_word: String,
+    current_position: usize,
+    /// A token that ended the current word, yielded right after it
+    pending: Option<Token>,
+}
+
+impl TokenIter<'_> {
+    /// Take the word built so far as a token, if any
+    fn take_word(&mut self) -> Option<Token> {
+        if self.current_word.is_empty() {
+            return None;
+        }
+        
+        // Determine if the word is a number (possibly with a decimal point)
+        let token_type = if self.current_word.chars().all(|c| c.is_numeric() || c == '.') {
+            TokenType::Number
+        } else if self.tokenizer.is_protected(&self.current_word) {
+            TokenType::Foreign
+        } else {
+            TokenType::Word
+        };
+        
+        Some(Token {
+            content: core::mem::take(&mut self.current_word),
+            token_type,
+            position: self.current_position,
+        })
+    }
+    
+    /// Yield the current word, if any, holding back the token that ended it
+    fn end_word_with(&mut self, token: Token) -> Token {
+        match self.take_word() {
+            Some(word) => {
+                self.pending = Some(token);
+                word
+            },
+            None => token,
+        }
     }
 }
 
+impl Iterator for TokenIter<'_> {
+    type Item = Token;
+    
+    fn next(&mut self) -> Option<Token> {
+        if let Some(token) = self.pending.take() {
+            return Some(token);
+        }
+        
+        let text = self.text;
+        while self.i < text.len() {
+            let i = self.i;
+            
+            // Get the current character
+            let c = text[i..].chars().next().unwrap();
+            let char_len = c.len_utf8();
+            
+            // Special case: Check for diacritics that should attach to the previous word
+            if !self.current_word.is_empty() && (c == '^' || c == ':' || c == '`') {
+                // Special case for Khanda Ta (T``)
+                if c == '`' && i + 1 < text.len() && text[i + 1..].starts_with('`') && self.current_word.ends_with('T') {
+                    // Add the `` to mark it as Khanda Ta
+                    self.current_word.push_str("``");
+                    self.i += 2; // Skip both backticks
+                    continue;
+                }
+                
+                // Handle ^ (Chandrabindu) and : (Visarga) as part of the word
+                if c == '^' || c == ':' {
+                    self.current_word.push(c);
+                    self.i += char_len;
+                    continue;
+                }
+            }
+            
+            // Special case: Check for hasanta sequence (,,)
+            if c == ',' && i + 1 < text.len() && text[i + 1..].starts_with(',') {
+                // If we're in a word context and there's a consonant before this
+                if !self.current_word.is_empty() {
+                    // Add the sequence to the current word
+                    self.current_word.push_str(",,");
+                    self.i += 2; // Skip both commas
+                    continue;
+                } else {
+                    // If we're not in a word context, the first comma is regular punctuation
+                    // and the next one is handled on the following call
+                    self.i += 1;
+                    self.current_position = self.i;
+                    return Some(Token {
+                        content: ",".to_string(),
+                        token_type: TokenType::Punctuation,
+                        position: i,
+                    });
+                }
+            }
+            
+            // An escaped double r ("r\r") stays one word, see `Tokenizer::tokenize_word`
+            if c == '\\' && self.current_word.ends_with('r') && text[i + 1..].starts_with('r') {
+                self.current_word.push(c);
+                self.i += char_len;
+                continue;
+            }
+            
+            // An apostrophe between two letters of a word stays in it unless
+            // it is punctuation, see `Tokenizer::with_apostrophe_policy`
+            if c == '\'' && self.tokenizer.apostrophe != ApostrophePolicy::Punctuation &&
+               self.current_word.ends_with(|prev: char| prev.is_alphabetic()) &&
+               text[i + 1..].starts_with(|next: char| next.is_alphabetic()) {
+                self.current_word.push(c);
+                self.i += char_len;
+                continue;
+            }
+            
+            // A hyphen between two word fragments (e.g. "kosmo-biggan") keeps them
+            // together as one hyphenated word; each fragment forms its own clusters
+            if c == '-' && !self.current_word.is_empty() &&
+               text[i + 1..].chars().next().is_some_and(|next| next.is_alphabetic()) {
+                self.current_word.push(c);
+                self.i += char_len;
+                continue;
+            }
+            
+            // A dot followed by a digit inside a number (e.g. "3.5") is a decimal point
+            if c == '.' && self.current_word.chars().all(|c| c.is_ascii_digit()) &&
+               text[i + 1..].starts_with(|next: char| next.is_ascii_digit()) {
+                if self.current_word.is_empty() {
+                    self.current_position = i;
+                }
+                self.current_word.push(c);
+                self.i += char_len;
+                continue;
+            }
+            
+            // A dot between vowels that would combine (e.g. "kO.I") is a hiatus
+            // marker that keeps them apart, not a sentence-ending dari
+            if c == '.' && self.tokenizer.is_hiatus(&self.current_word, &text[i + 1..]) {
+                self.current_word.push(c);
+                self.i += char_len;
+                continue;
+            }
+            
+            // A run of marks such as "..." or "?!" is one punctuation token
+            if let Some(run) = PUNCTUATION_RUNS.iter().find(|run| text[i..].starts_with(**run)) {
+                self.i += run.len();
+                let token = self.end_word_with(Token {
+                    content: run.to_string(),
+                    token_type: TokenType::Punctuation,
+                    position: i,
+                });
+                self.current_position = self.i;
+                return Some(token);
+            }
+            
+            self.i += char_len;
+            
+            let token_type = if c.is_whitespace() {
+                TokenType::Whitespace
+            } else if c.is_ascii_punctuation() {
+                TokenType::Punctuation
+            } else if !c.is_alphanumeric() && !self.current_word.is_empty() {
+                // Special symbol
+                TokenType::Symbol
+            } else {
+                // If we have an empty current word, update the position
+                if self.current_word.is_empty() {
+                    self.current_position = i;
+                }
+                // Add the character to the current word
+                self.current_word.push(c);
+                continue;
+            };
+            
+            // End the current word, if any, and yield the separator after it
+            let token = self.end_word_with(Token {
+                content: c.to_string(),
+                token_type,
+                position: i,
+            });
+            self.current_position = i + char_len;
+            return Some(token);
+        }
+        
+        // Add any remaining word
+        self.take_word()
+    }
+}
+
+/// Whether a sequence can be registered as a special form: one or more letters
+pub(crate) fn is_special_sequence(sequence: &str) -> bool {
+    !sequence.is_empty() && sequence.chars().all(char::is_alphabetic)
+}
+
 impl Default for Tokenizer {
     fn default() -> Self {
         Self::new()
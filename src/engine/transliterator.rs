@@ -4,34 +4,408 @@
 //! 
 //! For detailed implementation rules, see docs/simplified_rules.md
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::ops::Range;
+use serde::{Serialize, Deserialize};
+use unicode_segmentation::UnicodeSegmentation;
 use crate::definitions::{
     consonants, consonant_system, ConsonantSystem,
-    vowels, BengaliVowel,
-    diacritics, symbols, numerals, special_rules
+    vowels, vowel_vowel_combinations, BengaliVowel,
+    diacritics, symbols, numerals, ordinal_suffix, special_rules, Scheme, SHARED_PUNCTUATION
 };
+use super::dictionary::Dictionary;
 use super::sanitizer::{Sanitizer, SanitizeResult};
-use super::tokenizer::{Tokenizer, Token, TokenType, PhoneticUnit, PhoneticUnitType};
+use super::tokenizer::{is_special_sequence, ApostrophePolicy, Tokenizer, Token, TokenType, PhoneticUnit, PhoneticUnitType};
+#[cfg(feature = "metrics")]
+use super::stats::{Stats, StatsSnapshot};
+
+/// Policy for rendering phonetic units that have no Bengali mapping
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum UnknownPolicy {
+    /// Keep the original text in the output (default)
+    #[default]
+    Passthrough,
+    /// Remove the unit from the output
+    Drop,
+    /// Replace the unit with the given marker, e.g. '\u{FFFD}'
+    Replace(char),
+}
+
+/// Case folding applied to the input before tokenization
+///
+/// The Avro-style scheme is case-sensitive: `T`/`t`, `D`/`d`, `N`/`n`, `I`/`i`
+/// and others map to different Bengali letters. Folding lets users who type in
+/// all-lowercase (or capitalize the first word of a sentence) get sensible
+/// output, at the cost of those distinctions.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum CaseFoldProfile {
+    /// No folding, every case distinction of the scheme is kept (default)
+    #[default]
+    Sensitive,
+    /// Fold uppercase letters to their lowercase forms
+    ///
+    /// Retroflex consonants become dental (`T` -> ত), long vowels become
+    /// short (`I` -> ই), `S`/`Sh` become শ, `R` becomes র, and `Ng`/`NG`
+    /// become the anusvara `ng`. Two notations are kept as typed because they
+    /// have no lowercase equivalent: `O` (ও, and the `OI`/`OU` diphthongs) and
+    /// the Khanda Ta notation `T```.
+    Simple,
+}
+
+/// How a word-final consonant without a vowel is written
+///
+/// Standard Bengali orthography leaves the hasant off a final consonant even
+/// though the inherent vowel isn't pronounced: নাম, not নাম্.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum FinalConsonantPolicy {
+    /// Write the bare consonant with no mark, as standard orthography does (default)
+    #[default]
+    Inherent,
+    /// Write the consonant with a hasant (্), marking the vowel as suppressed
+    Hasant,
+}
+
+/// The sequence written for the hasant (virama) when it joins consonants
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum ViramaStyle {
+    /// The plain hasant (্), letting the font form the conjunct (default)
+    #[default]
+    Standard,
+    /// The hasant followed by a zero width joiner (U+200D), requesting a half form
+    WithZwj,
+    /// The hasant followed by a zero width non-joiner (U+200C), keeping the virama visible
+    Visible,
+}
+
+/// The script a part of the output is written in
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Script {
+    /// Bengali script (default)
+    #[default]
+    Bengali,
+    /// Latin script, keeping the input as typed
+    Latin,
+}
+
+/// The form a vowel is written in
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum VowelForm {
+    /// The vowel sign (kar) attached to a consonant, e.g. ি
+    Dependent,
+    /// The full vowel letter, e.g. ই
+    Independent,
+}
+
+/// The Bengali output produced by a single fragment of the input
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Annotation {
+    /// The Roman input fragment
+    pub roman: String,
+    /// The Bengali text this fragment produced
+    pub bengali: String,
+    /// The type of phonetic unit the fragment was recognized as
+    pub unit_type: PhoneticUnitType,
+    /// The index of the phonetic unit within its word, for the units of a word
+    #[serde(default)]
+    pub unit_index: Option<usize>,
+}
+
+/// The kind of problem reported by a [`Warning`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum WarningKind {
+    /// A fragment without a Bengali mapping, handled by the [`UnknownPolicy`]
+    UnknownFragment,
+    /// A recognized unit that could not be rendered and was kept as typed
+    Fallback,
+}
+
+/// A soft problem found while transliterating, which doesn't stop the output
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Warning {
+    /// What went wrong
+    pub kind: WarningKind,
+    /// The Roman input fragment the warning is about
+    pub fragment: String,
+    /// The byte position of the fragment in the input
+    pub position: usize,
+}
+
+/// Maps positions in transliterated output back to the input, built by
+/// [`Transliterator::transliterate_with_source_map`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SourceMap {
+    /// Byte offset in the output where each segment starts, with the 1-based
+    /// line and column of the input it came from, in output order
+    segments: Vec<(usize, usize, usize)>,
+}
+
+impl SourceMap {
+    /// The 1-based line and column of the input that produced the output at
+    /// byte offset `pos`
+    ///
+    /// Positions are resolved to the start of the token that produced them, and
+    /// columns count characters. A position past the end maps to the last token.
+    pub fn output_pos_to_input(&self, pos: usize) -> (usize, usize) {
+        let index = self.segments.partition_point(|&(start, _, _)| start <= pos);
+        self.segments.get(index.saturating_sub(1)).map_or((1, 1), |&(_, line, column)| (line, column))
+    }
+}
+
+/// A syllable of a word, found by [`Transliterator::syllables`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Syllable {
+    /// The phonetic units of the syllable, in order
+    pub units: Vec<PhoneticUnit>,
+    /// The Roman text the syllable was read from, after case folding and
+    /// scheme conversion
+    pub roman: String,
+    /// The Bengali text of the syllable
+    pub bengali: String,
+}
+
+impl Syllable {
+    /// Whether the syllable ends in its vowel, e.g. কা
+    ///
+    /// A syllable without a vowel unit is open when its consonants carry the
+    /// inherent vowel, as in ক্ক, and closed when they end in a hasant.
+    pub fn is_open(&self) -> bool {
+        // A chandrabindu nasalizes the vowel without closing the syllable
+        let Some(last) = self.units.iter().rev().find(|unit| unit.text != "^") else {
+            return false;
+        };
+        
+        if unit_has_vowel(&last.unit_type) {
+            true
+        } else if self.units.iter().any(|unit| unit_has_vowel(&unit.unit_type)) {
+            false
+        } else {
+            !last.text.ends_with(",,")
+        }
+    }
+    
+    /// Whether the syllable ends in a consonant after its vowel or in a hasant, e.g. কক্
+    pub fn is_closed(&self) -> bool {
+        !self.is_open()
+    }
+}
+
+/// Typographic marks substituted for plain punctuation, all off by default
+///
+/// Marks can be added in later versions, so the options are built with the
+/// `with_*` methods, e.g. `TypographyOptions::default().with_em_dash(true)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub struct TypographyOptions {
+    /// Write `--` as an em dash (—)
+    pub em_dash: bool,
+    /// Write straight double quotes as curly ones (“ ”), opening after
+    /// whitespace or an opening bracket and at the start of the text
+    pub curly_quotes: bool,
+    /// Write `...` as an ellipsis (…)
+    pub ellipsis: bool,
+    /// Write `?!` and `!?` as an interrobang (‽)
+    pub interrobang: bool,
+}
+
+impl TypographyOptions {
+    /// Enable or disable writing `--` as an em dash
+    pub fn with_em_dash(mut self, enabled: bool) -> Self {
+        self.em_dash = enabled;
+        self
+    }
+    
+    /// Enable or disable curly double quotes
+    pub fn with_curly_quotes(mut self, enabled: bool) -> Self {
+        self.curly_quotes = enabled;
+        self
+    }
+    
+    /// Enable or disable writing `...` as an ellipsis
+    pub fn with_ellipsis(mut self, enabled: bool) -> Self {
+        self.ellipsis = enabled;
+        self
+    }
+    
+    /// Enable or disable writing `?!` and `!?` as an interrobang
+    pub fn with_interrobang(mut self, enabled: bool) -> Self {
+        self.interrobang = enabled;
+        self
+    }
+}
+
+/// Options for [`Transliterator::format_number`]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct NumberFormatOptions {
+    /// Digits after the decimal point, or `None` for as many as the value needs
+    pub decimals: Option<usize>,
+    /// Append a percent sign
+    pub percent: bool,
+    /// Currency symbol written before the digits, e.g. "৳"
+    pub currency: Option<String>,
+}
+
+/// The kind of consonant cluster a conjunct unit forms
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConjunctType {
+    /// Consonants joined with a hasant, e.g. ক্র in `kr`
+    Regular,
+    /// A cluster with its own special form, e.g. ক্ষ (`kkh`) or জ্ঞ (`gg`)
+    Special,
+    /// A reph (র্) over a consonant, e.g. র্য in `rrz`
+    Reph,
+}
+
+/// The conjuncts found in a word, counted by [`ConjunctType`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ConjunctProfile {
+    /// Conjuncts of consonants joined with a hasant
+    pub regular: usize,
+    /// Special-form conjuncts such as ক্ষ and জ্ঞ
+    pub special: usize,
+    /// Reph conjuncts
+    pub reph: usize,
+}
+
+impl ConjunctProfile {
+    /// The number of conjuncts of the given type
+    pub fn count(&self, conjunct_type: ConjunctType) -> usize {
+        match conjunct_type {
+            ConjunctType::Regular => self.regular,
+            ConjunctType::Special => self.special,
+            ConjunctType::Reph => self.reph,
+        }
+    }
+    
+    /// The number of conjuncts of any type
+    pub fn total(&self) -> usize {
+        self.regular + self.special + self.reph
+    }
+    
+    /// Whether the word contains any special-form conjunct
+    pub fn has_special(&self) -> bool {
+        self.special > 0
+    }
+}
+
+/// A problem with a custom mapping, found by [`Transliterator::validate_mappings`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MappingConflict {
+    /// The key is already a recognized sequence, whose mapping it would replace
+    ShadowsBuiltin {
+        key: String,
+    },
+    /// One of the key and another sequence starts with the other, so longest
+    /// matching reads input containing the longer one differently
+    PrefixCollision {
+        key: String,
+        other: String,
+    },
+}
+
+/// Input fragments without a Bengali mapping, found by strict transliteration
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("{}", describe_unmappable(.fragments))]
+pub struct UnmappableError {
+    /// Every unmappable fragment with its byte position, in input order
+    pub fragments: Vec<(String, usize)>,
+}
+
+// Helper function to describe the first fragment of an UnmappableError, which
+// can be built without any
+fn describe_unmappable(fragments: &[(String, usize)]) -> String {
+    match fragments.first() {
+        Some((fragment, position)) => format!("no Bengali mapping for '{}' at position {}", fragment, position),
+        None => "no Bengali mapping for part of the input".to_string(),
+    }
+}
 
 /// Main transliterator that performs the Roman to Bengali conversion
 #[allow(dead_code)]  // Fields will be used when we implement the full transliteration
+#[derive(Clone)]
 pub struct Transliterator {
     // Structured phonetic data
     consonant_system: ConsonantSystem,
-    vowels: HashMap<&'static str, BengaliVowel>,
+    vowels: &'static phf::Map<&'static str, BengaliVowel>,
     
     // Lookup tables for conversion
-    consonants: HashMap<&'static str, &'static str>,
-    diacritics: HashMap<&'static str, &'static str>,
-    symbols: HashMap<&'static str, &'static str>,
-    numerals: HashMap<&'static str, &'static str>,
-    special_rules: HashMap<&'static str, &'static str>,
+    consonants: &'static phf::Map<&'static str, &'static str>,
+    diacritics: &'static phf::Map<&'static str, &'static str>,
+    symbols: &'static phf::Map<&'static str, &'static str>,
+    numerals: &'static phf::Map<&'static str, &'static str>,
+    special_rules: &'static phf::Map<&'static str, &'static str>,
     
     // Input sanitizer
     sanitizer: Sanitizer,
     
     // Tokenizer
     tokenizer: Tokenizer,
+    
+    // Profiling aid: run the pipeline but return the input unchanged
+    passthrough: bool,
+    
+    // How to render units without a mapping
+    unknown_policy: UnknownPolicy,
+    
+    // Case folding applied before tokenization
+    case_fold: CaseFoldProfile,
+    
+    // Romanization scheme of the input
+    scheme: Scheme,
+    
+    // Whether punctuation is converted to its Bengali equivalent
+    punctuation_conversion: bool,
+    
+    // The script words and numbers are written in
+    letter_script: Script,
+    
+    // Whether a sentence-ending '.' becomes the dari (।)
+    dari_conversion: bool,
+    
+    // Typographic marks substituted for plain punctuation
+    typography: TypographyOptions,
+    
+    // Whether each word is written as its Roman text with the Bengali in parentheses
+    interlinear: bool,
+    
+    // Whether runs of whitespace are written as a single space
+    collapse_whitespace: bool,
+    
+    // Whether a collapsed run containing a newline is written as a newline
+    keep_newlines: bool,
+    
+    // Whether "rr" before a consonant is written as a reph
+    reph: bool,
+    
+    // Whether a reph over য, য় or র is joined with a ZWJ
+    reph_zwj: bool,
+    
+    // Whether a word-initial য় is written as য
+    initial_ja: bool,
+    
+    // Whether 'w' after a consonant is written as a bo-fola
+    bo_fola: bool,
+    
+    // How a word-final bare consonant is written
+    final_consonant: FinalConsonantPolicy,
+    
+    // The sequence written for a hasant that joins consonants
+    virama_style: ViramaStyle,
+    
+    // Optional whole-word dictionary consulted before the rules
+    dictionary: Option<Dictionary>,
+    
+    // Special forms registered at runtime, overlaying the special rules table
+    registered_special_forms: BTreeMap<String, String>,
+    
+    // Marker written between the syllables of a word
+    syllable_marker: Option<char>,
+    
+    // Whether the inherent vowel is written out after each consonant that carries it
+    explicit_inherent_vowel: bool,
+    
+    // Usage counters, updated through `&self`
+    #[cfg(feature = "metrics")]
+    pub(crate) stats: Stats,
 }
 
 impl Transliterator {
@@ -54,20 +428,664 @@ impl Transliterator {
             
             // Tokenizer
             tokenizer: Tokenizer::default(),
+            
+            passthrough: false,
+            unknown_policy: UnknownPolicy::default(),
+            case_fold: CaseFoldProfile::default(),
+            scheme: Scheme::default(),
+            punctuation_conversion: true,
+            letter_script: Script::default(),
+            dari_conversion: true,
+            typography: TypographyOptions::default(),
+            interlinear: false,
+            collapse_whitespace: false,
+            keep_newlines: false,
+            reph: true,
+            reph_zwj: false,
+            initial_ja: false,
+            bo_fola: true,
+            final_consonant: FinalConsonantPolicy::default(),
+            virama_style: ViramaStyle::default(),
+            dictionary: None,
+            syllable_marker: None,
+            explicit_inherent_vowel: false,
+            registered_special_forms: BTreeMap::new(),
+            #[cfg(feature = "metrics")]
+            stats: Stats::default(),
+        }
+    }
+    
+    /// Enable or disable passthrough (identity) mode
+    ///
+    /// This is a profiling aid only. In passthrough mode the input is still
+    /// sanitized, tokenized and split into phonetic units, but no mapping to
+    /// Bengali is performed and the input is returned as output, as typed.
+    /// Timing a passthrough run gives the fixed cost of the pipeline, which can
+    /// be subtracted from a normal run to isolate the cost of the mapping itself.
+    pub fn with_passthrough(mut self, enabled: bool) -> Self {
+        self.passthrough = enabled;
+        self
+    }
+    
+    /// Set how phonetic units without a Bengali mapping are rendered
+    ///
+    /// The default, [`UnknownPolicy::Passthrough`], keeps the original text.
+    pub fn with_unknown_policy(mut self, policy: UnknownPolicy) -> Self {
+        self.unknown_policy = policy;
+        self
+    }
+    
+    /// Set the case folding profile applied to the input
+    ///
+    /// The default, [`CaseFoldProfile::Sensitive`], keeps the input as typed.
+    pub fn with_case_folding(mut self, profile: CaseFoldProfile) -> Self {
+        self.case_fold = profile;
+        self
+    }
+    
+    /// Set the Romanization scheme the input is written in
+    ///
+    /// The default is [`Scheme::Avro`]. Input in any other scheme is rewritten
+    /// to the equivalent Avro sequences after case folding, and the sanitizer
+    /// accepts the extra characters the scheme uses, in addition to the ones it
+    /// already accepts.
+    pub fn with_scheme(mut self, scheme: Scheme) -> Self {
+        self.scheme = scheme;
+        self.sanitizer = core::mem::take(&mut self.sanitizer).with_allowed_chars(scheme.extra_chars());
+        self
+    }
+    
+    /// Enable or disable converting punctuation to its Bengali equivalent
+    ///
+    /// Enabled by default. Conversion goes through the symbols table, so '.'
+    /// becomes the dari (।) and '$' the taka sign (৳); punctuation without an
+    /// entry, such as ',' and '?', is the same in Bengali and kept. When
+    /// disabled, all punctuation is left untouched.
+    pub fn with_punctuation_conversion(mut self, enabled: bool) -> Self {
+        self.punctuation_conversion = enabled;
+        self
+    }
+    
+    /// Set the script punctuation is written in, independently of the letters
+    ///
+    /// [`Script::Bengali`] (the default) converts punctuation as
+    /// [`Transliterator::with_punctuation_conversion`] does, and
+    /// [`Script::Latin`] leaves it untouched.
+    pub fn with_punctuation_script(self, script: Script) -> Self {
+        self.with_punctuation_conversion(script == Script::Bengali)
+    }
+    
+    /// Set the script words and numbers are written in, independently of punctuation
+    ///
+    /// With [`Script::Latin`] words and numbers are kept as typed, so only the
+    /// punctuation is converted. Defaults to [`Script::Bengali`].
+    pub fn with_letter_script(mut self, script: Script) -> Self {
+        self.letter_script = script;
+        self
+    }
+    
+    /// Enable or disable converting a sentence-ending '.' to the dari (।)
+    ///
+    /// Enabled by default. A '.' inside a number, as in "3.5", is a decimal
+    /// point and is kept either way.
+    pub fn with_dari_conversion(mut self, enabled: bool) -> Self {
+        self.dari_conversion = enabled;
+        self
+    }
+    
+    /// Set the typographic marks substituted for plain punctuation
+    ///
+    /// Applied to punctuation tokens independently of punctuation and dari
+    /// conversion. Off by default.
+    pub fn with_typography(mut self, typography: TypographyOptions) -> Self {
+        self.typography = typography;
+        self
+    }
+    
+    /// Enable or disable interlinear output, e.g. `amar (আমার)`
+    ///
+    /// Each word is written as typed, followed by its Bengali in parentheses.
+    /// Whitespace, punctuation and numbers are written as usual. Disabled by
+    /// default.
+    pub fn with_interlinear(mut self, enabled: bool) -> Self {
+        self.interlinear = enabled;
+        self
+    }
+    
+    /// Enable or disable collapsing runs of whitespace into a single space
+    ///
+    /// Disabled by default. Useful for HTML, where extra whitespace isn't
+    /// shown. Tabs and newlines are collapsed too, so input containing them
+    /// is transliterated rather than returned as rejected by the sanitizer.
+    pub fn with_whitespace_collapse(mut self, enabled: bool) -> Self {
+        self.collapse_whitespace = enabled;
+        self
+    }
+    
+    /// Write a collapsed run of whitespace containing a newline as one newline
+    ///
+    /// Disabled by default, so with [`Transliterator::with_whitespace_collapse`]
+    /// every run becomes a space. Each line is then transliterated separately.
+    pub fn with_newlines_kept(mut self, enabled: bool) -> Self {
+        self.keep_newlines = enabled;
+        self
+    }
+    
+    /// Enable or disable the reph (র্) over a following consonant
+    ///
+    /// Enabled by default. When disabled, "rr" is written as র with a visible
+    /// hasant (্ with a ZWNJ), so no conjunct forms: "korrmo" is কর্‌ম. Before
+    /// য it is র + ZWJ + hasant, the ya-phala form, as in র‍্যাব.
+    pub fn with_reph(mut self, enabled: bool) -> Self {
+        self.reph = enabled;
+        self
+    }
+    
+    /// Enable or disable the ZWJ-joined reph (র্\u{200D}) over য, য় and র
+    ///
+    /// Disabled by default. Some fonts render a plain reph over these
+    /// consonants ambiguously; the ZWJ asks for the reph form explicitly.
+    /// Unicode already defines the plain র + hasant + য as the reph form
+    /// (সূর্য), and conformant fonts render it so. The ZWJ adds a code point,
+    /// so text written with it no longer matches standard text when searched
+    /// or compared, which is why it is not the default.
+    pub fn with_reph_zwj(mut self, enabled: bool) -> Self {
+        self.reph_zwj = enabled;
+        self
+    }
+    
+    /// Write a word-initial `y` as য instead of য়
+    ///
+    /// Disabled by default. A `y` that starts a word never forms a ya-phala,
+    /// as there is no consonant before it; some spellings of names, such as
+    /// যমুনা, use য there instead. `z` is always য.
+    pub fn with_initial_ja(mut self, enabled: bool) -> Self {
+        self.initial_ja = enabled;
+        self
+    }
+    
+    /// Enable or disable ya-phala (য-ফলা) formation
+    ///
+    /// See [`Tokenizer::with_ya_phala`].
+    pub fn with_ya_phala(mut self, enabled: bool) -> Self {
+        self.tokenizer = self.tokenizer.with_ya_phala(enabled);
+        self
+    }
+    
+    /// Enable or disable fallback spellings for unmapped ASCII letters
+    ///
+    /// See [`Tokenizer::with_ascii_fallback`].
+    pub fn with_ascii_fallback(mut self, enabled: bool) -> Self {
+        self.tokenizer = self.tokenizer.with_ascii_fallback(enabled);
+        self
+    }
+    
+    /// Limit the number of consonants joined in one conjunct
+    ///
+    /// See [`Tokenizer::with_max_conjunct_length`].
+    pub fn with_max_conjunct_length(mut self, max: usize) -> Self {
+        self.tokenizer = self.tokenizer.with_max_conjunct_length(max);
+        self
+    }
+    
+    /// Read only uppercase "OI" and "OU" as diphthongs
+    ///
+    /// See [`Tokenizer::with_diphthong_case_sensitivity`].
+    pub fn with_diphthong_case_sensitivity(mut self, enabled: bool) -> Self {
+        self.tokenizer = self.tokenizer.with_diphthong_case_sensitivity(enabled);
+        self
+    }
+    
+    /// Keep the given words as typed instead of transliterating them
+    ///
+    /// See [`Tokenizer::with_protected_terms`].
+    pub fn with_protected_terms(mut self, terms: Vec<String>) -> Self {
+        self.tokenizer = self.tokenizer.with_protected_terms(terms);
+        self
+    }
+    
+    /// Match protected terms regardless of case
+    ///
+    /// See [`Tokenizer::with_protected_terms_ignore_case`].
+    pub fn with_protected_terms_ignore_case(mut self, enabled: bool) -> Self {
+        self.tokenizer = self.tokenizer.with_protected_terms_ignore_case(enabled);
+        self
+    }
+    
+    /// Set how an apostrophe inside a word is handled
+    ///
+    /// See [`Tokenizer::with_apostrophe_policy`].
+    pub fn with_apostrophe_policy(mut self, policy: ApostrophePolicy) -> Self {
+        self.tokenizer = self.tokenizer.with_apostrophe_policy(policy);
+        self
+    }
+    
+    /// Enable or disable bo-fola (ব-ফলা) formation
+    ///
+    /// Enabled by default. When disabled, a 'w' after a consonant is written
+    /// as a separate ব instead of joining the consonant.
+    pub fn with_bo_fola(mut self, enabled: bool) -> Self {
+        self.bo_fola = enabled;
+        self
+    }
+    
+    /// Set how a word-final consonant without a vowel is written
+    ///
+    /// The default, [`FinalConsonantPolicy::Inherent`], matches standard
+    /// orthography and leaves the consonant bare.
+    pub fn with_final_hasant(mut self, policy: FinalConsonantPolicy) -> Self {
+        self.final_consonant = policy;
+        self
+    }
+    
+    /// Change the final consonant policy in place, returning the previous one
+    pub(crate) fn set_final_hasant(&mut self, policy: FinalConsonantPolicy) -> FinalConsonantPolicy {
+        core::mem::replace(&mut self.final_consonant, policy)
+    }
+    
+    /// Set the sequence written for a hasant that joins consonants
+    ///
+    /// The default, [`ViramaStyle::Standard`], writes the plain hasant. The
+    /// reph is not affected.
+    pub fn with_virama_style(mut self, style: ViramaStyle) -> Self {
+        self.virama_style = style;
+        self
+    }
+    
+    /// The hasant in the configured [`ViramaStyle`]
+    fn virama(&self) -> &'static str {
+        match self.virama_style {
+            ViramaStyle::Standard => "্",
+            ViramaStyle::WithZwj => "্\u{200D}",
+            ViramaStyle::Visible => VISIBLE_VIRAMA,
+        }
+    }
+    
+    /// The reph prefix, or র with a visible hasant when reph is disabled
+    fn reph(&self) -> &'static str {
+        if self.reph { "র্" } else { "র্\u{200C}" }
+    }
+    
+    /// The reph prefix written before `consonant`, see [`Transliterator::with_reph_zwj`]
+    ///
+    /// Without reph, র + ZWJ + hasant before য is the ya-phala form (র‍্য).
+    fn reph_over(&self, consonant: &str) -> &'static str {
+        match consonant {
+            "য" | "য়" | "র" if self.reph && self.reph_zwj => "র্\u{200D}",
+            "য" if !self.reph => "র\u{200D}্",
+            _ => self.reph(),
+        }
+    }
+    
+    /// Use a dictionary of whole words to disambiguate common words
+    ///
+    /// A word found in the dictionary is replaced by its entry instead of
+    /// being transliterated by the rules.
+    pub fn with_dictionary(mut self, dictionary: Dictionary) -> Self {
+        self.dictionary = Some(dictionary);
+        self
+    }
+    
+    /// Register a special form, such as an archaic ligature, written for `components`
+    ///
+    /// The Roman sequence is matched as a whole, like the built-in `kkh` (ক্ষ),
+    /// and takes precedence over the built-in special forms and over
+    /// splitting it into consonants. Empty `components`, or components with
+    /// anything other than letters, are ignored.
+    pub fn register_special(&mut self, components: &str, rendered: &str) {
+        if !is_special_sequence(components) {
+            log::warn!("Ignoring special form {:?}: only letters can be registered", components);
+            return;
+        }
+        self.tokenizer.register_special(components);
+        self.registered_special_forms.insert(components.to_string(), rendered.to_string());
+    }
+    
+    /// Register a special form, see [`Transliterator::register_special`]
+    pub fn with_special_form(mut self, components: &str, rendered: &str) -> Self {
+        self.register_special(components, rendered);
+        self
+    }
+    
+    /// Check custom mappings for keys that would change how existing input is read
+    ///
+    /// A key that is already recognized shadows it. A key collides with any
+    /// other sequence, recognized or among the overrides, of two or more
+    /// letters that it starts with or that starts with it: with "kh" built in,
+    /// "kha" would take the 'a' of "khal" away from the vowel. Single letters
+    /// are not reported, as every multi-letter key starts with one. Conflicts
+    /// are sorted by key.
+    pub fn validate_mappings(&self, overrides: &HashMap<String, String>) -> Vec<MappingConflict> {
+        let mut recognized: BTreeSet<&str> = self.tokenizer.vowel_patterns().into_iter()
+            .chain(self.tokenizer.consonant_patterns())
+            .chain(self.tokenizer.special_sequences().into_iter().map(|(sequence, _)| sequence))
+            .collect();
+        recognized.insert("o");
+        
+        let mut keys: Vec<&str> = overrides.keys().map(String::as_str).collect();
+        keys.sort_unstable();
+        
+        let mut conflicts = Vec::new();
+        for &key in &keys {
+            if recognized.contains(key) {
+                conflicts.push(MappingConflict::ShadowsBuiltin { key: key.to_string() });
+            }
+            
+            let others = recognized.iter().copied().chain(keys.iter().copied())
+                .filter(|&other| other != key && other.chars().count() > 1)
+                .filter(|&other| key.starts_with(other) || other.starts_with(key))
+                .collect::<BTreeSet<_>>();
+            conflicts.extend(others.into_iter().map(|other| MappingConflict::PrefixCollision {
+                key: key.to_string(),
+                other: other.to_string(),
+            }));
+        }
+        
+        conflicts
+    }
+    
+    /// The Bengali rendering of a special form, registered or built in
+    fn special_form(&self, roman: &str) -> Option<&str> {
+        self.registered_special_forms.get(roman).map(String::as_str)
+            .or_else(|| self.special_rules.get(roman).copied())
+    }
+    
+    /// Every special form with its Bengali rendering, registered ones first in sorted order
+    fn special_forms(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.registered_special_forms.iter().map(|(roman, bengali)| (roman.as_str(), bengali.as_str()))
+            .chain(self.special_rules.entries().map(|(roman, bengali)| (*roman, *bengali)))
+    }
+    
+    /// Write a marker between the syllables of each word, e.g. '·' for আ·মার
+    ///
+    /// A syllable starts at each vowel, or at the consonant or cluster written
+    /// before it, so a consonant without a vowel closes the previous syllable.
+    /// The marker is never placed inside a conjunct. `None` (the default)
+    /// writes no markers.
+    pub fn with_syllable_markers(mut self, marker: Option<char>) -> Self {
+        self.syllable_marker = marker;
+        self
+    }
+    
+    /// Write the inherent vowel as a visible "(অ)" after each consonant that carries it
+    ///
+    /// A teaching aid only: কমল (komol) is written ক(অ)ম(অ)ল(অ), while consonants
+    /// with a vowel sign or hasant, and those inside a conjunct, are left as
+    /// they are. Disabled by default.
+    pub fn with_explicit_inherent_vowel(mut self, enabled: bool) -> Self {
+        self.explicit_inherent_vowel = enabled;
+        self
+    }
+    
+    /// Whether the text is written in Bengali script
+    ///
+    /// True if the text has a Bengali character (U+0980 to U+09FF) and no
+    /// letters or digits of another script. Useful before applying Latin case
+    /// operations, which leave Bengali unchanged.
+    pub fn is_bengali(s: &str) -> bool {
+        Self::contains_bengali(s) && s.chars().all(|c| is_bengali_char(c) || !c.is_alphanumeric())
+    }
+    
+    /// Whether the text contains any Bengali character (U+0980 to U+09FF)
+    pub fn contains_bengali(s: &str) -> bool {
+        s.chars().any(is_bengali_char)
+    }
+    
+    /// Every recognized Roman input sequence with its Bengali output
+    ///
+    /// Aggregates the consonant, vowel, diacritic, symbol, numeral and special
+    /// rule tables. A vowel appears twice, once with its independent form and
+    /// once with its dependent sign. The list is sorted by Roman sequence, then
+    /// by Bengali output.
+    pub fn supported_mappings(&self) -> Vec<(String, String)> {
+        let tables = [self.consonants, self.diacritics, self.symbols, self.numerals, self.special_rules];
+        let mut mappings: BTreeSet<(String, String)> = tables.into_iter()
+            .flat_map(|table| table.entries())
+            .map(|(roman, bengali)| (roman.to_string(), bengali.to_string()))
+            .chain(self.registered_special_forms.iter().map(|(roman, bengali)| (roman.clone(), bengali.clone())))
+            .collect();
+        
+        for (roman, vowel) in self.vowels.entries() {
+            mappings.insert((roman.to_string(), vowel.independent.to_string()));
+            if let Some(dependent) = vowel.dependent {
+                mappings.insert((roman.to_string(), dependent.to_string()));
+            }
+        }
+        
+        mappings.into_iter().collect()
+    }
+    
+    /// Format a number with Bengali digits and lakh/crore grouping
+    ///
+    /// The last three digits of the integer part form a group and every two
+    /// digits before them another, so 1234567.5 is ১২,৩৪,৫৬৭.৫. A minus sign
+    /// goes before the currency symbol. An infinity is written as ∞, with its
+    /// sign, currency and percent, and NaN as সংখ্যা নয় ("not a number").
+    pub fn format_number(&self, value: f64, opts: NumberFormatOptions) -> String {
+        if value.is_nan() {
+            return "সংখ্যা নয়".to_string();
+        }
+        if value.is_infinite() {
+            let sign = if value < 0.0 { "-" } else { "" };
+            let currency = opts.currency.as_deref().unwrap_or_default();
+            let percent = if opts.percent { "%" } else { "" };
+            return format!("{}{}∞{}", sign, currency, percent);
+        }
+        
+        let digits = match opts.decimals {
+            Some(decimals) => format!("{:.*}", decimals, value.abs()),
+            None => value.abs().to_string(),
+        };
+        let (integer, fraction) = match digits.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (digits.as_str(), None),
+        };
+        
+        let mut result = String::with_capacity(digits.len() * 4);
+        // No sign on a value that rounds to zero
+        if value.is_sign_negative() && digits.bytes().any(|b| (b'1'..=b'9').contains(&b)) {
+            result.push('-');
+        }
+        if let Some(currency) = &opts.currency {
+            result.push_str(currency);
+        }
+        
+        let mut buffer = [0u8; 4];
+        let head = integer.len().saturating_sub(3);
+        for (i, digit) in integer.chars().enumerate() {
+            if i > 0 && i <= head && (head - i) % 2 == 0 {
+                result.push(',');
+            }
+            result.push_str(self.numerals.get(digit.encode_utf8(&mut buffer)).copied().unwrap_or_default());
+        }
+        
+        if let Some(fraction) = fraction {
+            result.push('.');
+            for digit in fraction.chars() {
+                result.push_str(self.numerals.get(digit.encode_utf8(&mut buffer)).copied().unwrap_or_default());
+            }
+        }
+        
+        if opts.percent {
+            result.push('%');
+        }
+        
+        result
+    }
+    
+    /// Write the ordinal of a number with Bengali digits, e.g. ১ম for 1st
+    ///
+    /// Emits the numeric-suffix form, not the spelled one (প্রথম): 1 to 10
+    /// take the irregular suffixes of their spelled forms (১ম, ২য়, ৩য়, ৪র্থ,
+    /// ৫ম, ৬ষ্ঠ, ৭ম to ১০ম) and every other number takes তম (১১তম, ২১তম).
+    /// The digits are not grouped. See [`Transliterator::format_number`] for
+    /// cardinals.
+    pub fn to_bengali_ordinal(&self, n: u64) -> String {
+        let mut buffer = [0u8; 4];
+        let mut ordinal: String = n.to_string().chars()
+            .map(|digit| self.numerals.get(digit.encode_utf8(&mut buffer)).copied().unwrap_or_default())
+            .collect();
+        ordinal.push_str(ordinal_suffix(n));
+        ordinal
+    }
+    
+    /// The dependent sign of a vowel on a dotted circle (U+25CC), e.g. ◌া for `A`
+    ///
+    /// Returns `None` for an unknown vowel and for the inherent vowel `o`,
+    /// which has no visible sign.
+    pub fn vowel_sign(&self, roman_vowel: &str) -> Option<String> {
+        self.vowels.get(roman_vowel)
+            .and_then(|vowel| vowel.dependent)
+            .filter(|dependent| !dependent.is_empty())
+            .map(|dependent| format!("\u{25CC}{}", dependent))
+    }
+    
+    /// The canonical Roman input for a single Bengali orthographic cluster
+    ///
+    /// Handles an independent vowel, a consonant or conjunct (optionally under
+    /// a reph) with an optional vowel sign, and a trailing chandrabindu,
+    /// anusvara or visarga. A lone consonant is given its inherent vowel (ক is
+    /// `ko`) while a conjunct is left bare (ক্ক is `kk`, র্ক is `rrk`). Where
+    /// several inputs produce a letter the lowercase one is preferred, then the
+    /// shortest. Returns `None` if the text isn't one such cluster, or if no
+    /// candidate input transliterates back to it.
+    pub fn cluster_to_roman(&self, cluster: &str) -> Option<String> {
+        let vowel_roman = |bengali: &str| canonical_roman(self.vowels.entries()
+            .filter(|(_, vowel)| vowel.independent == bengali || vowel.dependent == Some(bengali))
+            .map(|(roman, _)| *roman));
+        let consonant_roman = |bengali: &str| canonical_roman(self.consonants.entries()
+            .filter(|(_, consonant)| **consonant == bengali)
+            .map(|(roman, _)| *roman));
+        
+        let mut chars = cluster.chars().peekable();
+        let mut nucleus = String::new();
+        let mut parts: Vec<&str> = Vec::new();
+        let mut reph = false;
+        let mut buffer = [0u8; 4];
+        
+        let first = *chars.peek()?;
+        if let Some(roman) = vowel_roman(first.encode_utf8(&mut buffer)) {
+            // An independent vowel
+            nucleus.push_str(roman);
+            chars.next();
+        } else if self.diacritics.get("T``").is_some_and(|khanda_ta| cluster.starts_with(khanda_ta)) {
+            nucleus.push_str("T``");
+            chars.next();
+        } else {
+            let mut rest = cluster;
+            if let Some(after) = rest.strip_prefix("র্").filter(|after| !after.is_empty()) {
+                reph = true;
+                rest = after;
+            }
+            
+            // Consonants joined by a hasant, with the special forms like ক্ষ taken whole
+            loop {
+                let special = self.special_forms()
+                    .find(|(roman, bengali)| self.is_special_cluster(roman) && rest.starts_with(bengali));
+                let (roman, len) = match special {
+                    Some((roman, bengali)) => (roman, bengali.len()),
+                    None => {
+                        let c = rest.chars().next()?;
+                        // য after a hasant is the ya-phala
+                        let roman = match c {
+                            'য' if !parts.is_empty() => "y",
+                            _ => consonant_roman(c.encode_utf8(&mut buffer))?,
+                        };
+                        (roman, c.len_utf8())
+                    },
+                };
+                parts.push(roman);
+                rest = &rest[len..];
+                
+                match rest.strip_prefix('্') {
+                    Some(after) if !after.is_empty() => rest = after,
+                    _ => break,
+                }
+            }
+            
+            chars = rest.chars().peekable();
+            match chars.peek().and_then(|&c| vowel_roman(c.encode_utf8(&mut buffer))) {
+                Some(roman) => {
+                    nucleus.push_str(roman);
+                    chars.next();
+                },
+                None if parts.len() == 1 && !reph && !self.is_special_cluster(parts[0]) => nucleus.push('o'),
+                None => {},
+            }
+        }
+        
+        // Chandrabindu, anusvara and visarga follow the vowel
+        for c in chars {
+            let bengali: &str = c.encode_utf8(&mut buffer);
+            let roman = ["^", "ng", ":"].into_iter()
+                .find(|roman| self.diacritics.get(roman) == Some(&bengali))?;
+            nucleus.push_str(roman);
+        }
+        
+        let prefix = if reph { "rr" } else { "" };
+        [parts.concat(), parts.join(",,")].into_iter()
+            .map(|consonants| format!("{}{}{}", prefix, consonants, nucleus))
+            .find(|roman| {
+                let mut bengali = String::new();
+                self.transliterate_word_into(roman, &mut bengali, None, None, self.final_consonant);
+                bengali == cluster
+            })
+    }
+    
+    /// Look up a word in the configured dictionary, if any
+    fn dictionary_entry(&self, word: &str) -> Option<&str> {
+        self.dictionary.as_ref().and_then(|dictionary| dictionary.get(word))
+    }
+    
+    /// Apply case folding and convert the input scheme to Avro
+    fn prepare_input(&self, text: String) -> String {
+        self.scheme.to_avro(self.fold_case(text))
+    }
+    
+    /// Apply the configured case folding profile to the input
+    ///
+    /// Protected terms are matched before folding, so they keep their case.
+    fn fold_case(&self, text: String) -> String {
+        match self.case_fold {
+            CaseFoldProfile::Sensitive => text,
+            CaseFoldProfile::Simple => {
+                let mut folded = String::with_capacity(text.len());
+                
+                if self.tokenizer.has_protected_terms() {
+                    for token in self.tokenizer.token_iter(&text) {
+                        if token.token_type == TokenType::Foreign {
+                            folded.push_str(&token.content);
+                        } else {
+                            push_folded(&token.content, &mut folded);
+                        }
+                    }
+                } else {
+                    push_folded(&text, &mut folded);
+                }
+                
+                folded
+            }
+        }
+    }
+    
+    /// Run the phonetic tokenizer over every word without producing output
+    fn tokenize_words_only(&self, tokens: &[Token]) {
+        for token in tokens.iter().filter(|t| t.token_type == TokenType::Word) {
+            self.tokenizer.tokenize_word(&token.content);
         }
     }
     
     /// Create a conjunct by adding hasant between consonants
     #[allow(dead_code)]
     fn create_conjunct(&self, c1: &str, c2: &str) -> String {
-        let hasant = self.diacritics.get(",,").unwrap_or(&"্");
+        let hasant = self.virama();
         format!("{}{}{}", c1, hasant, c2)
     }
     
     /// Add a hasant to a consonant (used when explicitly adding hasant with ,,)
     #[allow(dead_code)]
     fn add_hasant(&self, consonant: &str) -> String {
-        let hasant = self.diacritics.get(",,").unwrap_or(&"্");
+        let hasant = self.virama();
         format!("{}{}", consonant, hasant)
     }
     
@@ -82,7 +1100,7 @@ impl Transliterator {
     #[allow(dead_code)]
     fn create_ya_phala(&self, consonant: &str) -> String {
         // For y-phola, we join the consonant with য using hasant
-        let hasant = self.diacritics.get(",,").unwrap_or(&"্");
+        let hasant = self.virama();
         let ya = "য"; // Use regular yo (য) for jo-phola, NOT antastha ya (য়)
         format!("{}{}{}", consonant, hasant, ya)
     }
@@ -91,78 +1109,167 @@ impl Transliterator {
     #[allow(dead_code)]
     fn create_ba_phala(&self, consonant: &str) -> String {
         // For w-phola, we join the consonant with ব using hasant
-        let hasant = self.diacritics.get(",,").unwrap_or(&"্");
+        let hasant = self.virama();
         let ba = self.consonants.get("b").unwrap_or(&"ব");
         format!("{}{}{}", consonant, hasant, ba)
     }
     
     /// Transliterate Roman text to Bengali
     pub fn transliterate(&self, text: &str) -> String {
+        let mut result = String::new();
+        self.transliterate_into(text, &mut result);
+        result
+    }
+    
+    /// Transliterate Roman text to Bengali into a caller-provided buffer
+    ///
+    /// The buffer is cleared first. Reusing one buffer across calls avoids
+    /// allocating a new output string for every input in hot loops; the output
+    /// is identical to [`Transliterator::transliterate`].
+    pub fn transliterate_into(&self, text: &str, out: &mut String) {
+        self.transliterate_tokens_into(text, out, |token, out| self.transliterate_token_into(token, out));
+        
+        #[cfg(feature = "metrics")]
+        self.stats.record_call(text, out);
+    }
+    
+    /// Transliterate Roman text to Bengali with a final consonant policy for this call only
+    ///
+    /// The output is that of [`Transliterator::transliterate`] on a
+    /// transliterator built with [`Transliterator::with_final_hasant`], without
+    /// changing this one.
+    pub fn transliterate_with_final_policy(&self, text: &str, policy: FinalConsonantPolicy) -> String {
+        let mut out = String::new();
+        self.transliterate_tokens_into(text, &mut out, |token, out| self.transliterate_token_with_policy(token, out, policy));
+        
+        #[cfg(feature = "metrics")]
+        self.stats.record_call(text, &out);
+        
+        out
+    }
+    
+    /// Transliterate text, letting `f` replace the Bengali written for each phonetic unit
+    ///
+    /// `f` is called once per unit of every word, in left-to-right order
+    /// through the text, with the unit and the Bengali the engine wrote for
+    /// it. Returning `Some` writes the replacement instead. Numbers,
+    /// punctuation and dictionary words have no units and are written as
+    /// usual.
+    pub fn transliterate_with<F>(&self, text: &str, mut f: F) -> String
+    where
+        F: FnMut(&PhoneticUnit, &str) -> Option<String>,
+    {
+        let mut out = String::with_capacity(text.len() * 3);
+        self.transliterate_tokens_into(text, &mut out, |token, out| match token.token_type {
+            TokenType::Word if self.letter_script != Script::Latin && self.dictionary_entry(&token.content).is_none() => {
+                self.transliterate_word_into(&token.content, out, None, Some(&mut f), self.final_consonant);
+            },
+            _ => self.transliterate_token_into(token, out),
+        });
+        out
+    }
+    
+    /// The usage counters accumulated since the engine was created or last reset
+    ///
+    /// Every call of [`Transliterator::transliterate`] and
+    /// [`Transliterator::transliterate_into`] is counted, as are the calls and
+    /// cache hits of a [`DocumentTransliterator`](super::DocumentTransliterator).
+    #[cfg(feature = "metrics")]
+    pub fn stats(&self) -> StatsSnapshot {
+        self.stats.snapshot()
+    }
+    
+    /// Set the usage counters back to zero
+    #[cfg(feature = "metrics")]
+    pub fn reset_stats(&self) {
+        self.stats.reset();
+    }
+    
+    /// Run the pipeline of [`Transliterator::transliterate_into`], writing each
+    /// token with `token_into`
+    pub(crate) fn transliterate_tokens_into<F>(&self, text: &str, out: &mut String, mut token_into: F)
+    where
+        F: FnMut(&Token, &mut String),
+    {
+        out.clear();
+        
+        if !self.collapse_whitespace {
+            self.push_tokens(text, out, &mut token_into);
+            return;
+        }
+        
+        // The sanitizer rejects newlines, so kept newlines split the text into lines
+        let collapsed = collapse_whitespace(text, self.keep_newlines);
+        for (index, line) in collapsed.split('\n').enumerate() {
+            if index > 0 {
+                out.push('\n');
+            }
+            self.push_tokens(line, out, &mut token_into);
+        }
+    }
+    
+    /// Transliterate `text`, appending each token to `out` with `token_into`
+    fn push_tokens<F>(&self, text: &str, out: &mut String, token_into: &mut F)
+    where
+        F: FnMut(&Token, &mut String),
+    {
+        // Empty and whitespace-only input is returned unchanged, including
+        // whitespace the sanitizer doesn't accept, such as tabs and newlines
+        if text.trim().is_empty() {
+            out.push_str(text);
+            return;
+        }
+        
         // First sanitize the input
         match self.sanitize(text) {
             Ok(sanitized) => {
+                let sanitized = self.prepare_input(sanitized);
+                
                 // Process the sanitized text using the tokenizer
                 let tokens = self.tokenizer.tokenize_text(&sanitized);
                 
-                // Process each token based on its type
-                let mut result = String::new();
+                if self.passthrough {
+                    // The input is written as typed, before case folding
+                    self.tokenize_words_only(&tokens);
+                    out.push_str(text);
+                    return;
+                }
                 
-                for token in tokens {
-                    match token.token_type {
-                        TokenType::Word => {
-                            result.push_str(&self.transliterate_word(&token.content));
-                        },
-                        TokenType::Whitespace => {
-                            result.push_str(&token.content);
-                        },
-                        TokenType::Punctuation => {
-                            // For most punctuation, keep it as is
-                            // However, some punctuation might need to be converted
-                            if let Some(bengali_symbol) = self.symbols.get(token.content.as_str()) {
-                                result.push_str(bengali_symbol);
-                            } else {
-                                result.push_str(&token.content);
-                            }
-                        },
-                        TokenType::Number => {
-                            // Convert numbers to Bengali numerals if applicable
-                            let mut numeral_result = String::new();
-                            let mut converted = false;
-                            
-                            for digit in token.content.chars() {
-                                let digit_str = digit.to_string();
-                                if let Some(bengali_digit) = self.numerals.get(digit_str.as_str()) {
-                                    numeral_result.push_str(bengali_digit);
-                                    converted = true;
-                                } else {
-                                    numeral_result.push(digit);
-                                }
-                            }
-                            
-                            if converted {
-                                result.push_str(&numeral_result);
-                            } else {
-                                result.push_str(&token.content);
-                            }
-                        },
-                        TokenType::Symbol => {
-                            // Convert symbols if applicable
-                            if let Some(bengali_symbol) = self.symbols.get(token.content.as_str()) {
-                                result.push_str(bengali_symbol);
-                            } else {
-                                result.push_str(&token.content);
-                            }
-                        },
+                // Process each token based on its type
+                for token in &tokens {
+                    if token.token_type == TokenType::Punctuation {
+                        let mark = match token.content.as_str() {
+                            "--" if self.typography.em_dash => Some('—'),
+                            "..." if self.typography.ellipsis => Some('…'),
+                            "?!" | "!?" if self.typography.interrobang => Some('‽'),
+                            _ => None,
+                        };
+                        if let Some(mark) = mark {
+                            out.push(mark);
+                            continue;
+                        }
+                        if self.typography.curly_quotes && token.content == "\"" {
+                            let opening = out.chars().next_back()
+                                .is_none_or(|c| c.is_whitespace() || "([{—".contains(c));
+                            out.push(if opening { '“' } else { '”' });
+                            continue;
+                        }
                     }
+                    if self.interlinear && token.token_type == TokenType::Word {
+                        out.push_str(&token.content);
+                        out.push_str(" (");
+                        token_into(token, out);
+                        out.push(')');
+                        continue;
+                    }
+                    token_into(token, out);
                 }
-                
-                result
             },
             Err(e) => {
                 // If sanitization failed, return the original text
                 // In a real application, you might want to handle this differently
-                eprintln!("Transliteration error: {}", e);
-                text.to_string()
+                log::error!("Transliteration error: {}", e);
+                out.push_str(text);
             }
         }
     }
@@ -172,94 +1279,746 @@ impl Transliterator {
         self.tokenizer.tokenize_text(text)
     }
     
+    /// Sanitize and tokenize the input text without transliterating it
+    ///
+    /// Runs the same steps as [`Transliterator::transliterate`] up to
+    /// tokenization, including case folding and scheme conversion, so the
+    /// tokens match the ones that would be transliterated. Input the sanitizer
+    /// rejects is tokenized as is.
+    pub fn tokenize_only(&self, text: &str) -> Vec<Token> {
+        let sanitized = self.sanitizer.sanitize(text).unwrap_or_else(|_| text.to_string());
+        self.tokenizer.tokenize_text(&self.prepare_input(sanitized))
+    }
+    
     /// Tokenize a word into phonetic units
     pub fn tokenize_phonetic(&self, word: &str) -> Vec<PhoneticUnit> {
         self.tokenizer.tokenize_word(word)
     }
     
-    /// Sanitize the input text, ensuring it contains only allowed characters
-    pub fn sanitize(&self, text: &str) -> SanitizeResult {
-        self.sanitizer.sanitize(text)
+    /// Count the conjuncts in a word by [`ConjunctType`]
+    ///
+    /// The word is case folded and converted from the configured scheme first.
+    /// Special forms are the consonant clusters of the special rules table, so
+    /// the anusvara `ng` and `aya` (অ্যা) are not counted.
+    pub fn conjunct_profile(&self, word: &str) -> ConjunctProfile {
+        let mut profile = ConjunctProfile::default();
+        
+        for unit in self.tokenizer.tokenize_word(&self.prepare_input(word.to_string())) {
+            match unit.unit_type {
+                PhoneticUnitType::Conjunct
+                | PhoneticUnitType::ConjunctWithVowel
+                | PhoneticUnitType::ConjunctWithTerminator => profile.regular += 1,
+                PhoneticUnitType::RephOverConsonant
+                | PhoneticUnitType::RephOverConsonantWithVowel
+                | PhoneticUnitType::RephOverConsonantWithTerminator => profile.reph += 1,
+                PhoneticUnitType::SpecialForm if self.is_special_cluster(&unit.text) => profile.special += 1,
+                _ => {},
+            }
+        }
+        
+        profile
+    }
+    
+    /// Count the pronounced syllables (vowel nuclei) of a word, e.g. for meter
+    ///
+    /// Every vowel in the word is one nucleus: an independent vowel, a vowel
+    /// sign, and `o`, written or not (so `bhalo` has 2). A consonant without a
+    /// vowel adds none, whether it is word-final (`nam` has 1) or carries a
+    /// hasant (`bak,,` has 1). A word without any vowel is pronounced with the
+    /// inherent vowel অ of its consonants and counts 1 (`kk`, ক্ক), unless its
+    /// only consonants carry a hasant. The anusvara, visarga and chandrabindu
+    /// add nothing.
+    pub fn pronounced_syllable_count(&self, word: &str) -> usize {
+        let units = self.tokenizer.tokenize_word(&self.prepare_input(word.to_string()));
+        let nuclei = units.iter().filter(|unit| unit_has_vowel(&unit.unit_type)).count();
+        
+        let has_inherent = units.iter().any(|unit| match unit.unit_type {
+            PhoneticUnitType::Consonant
+            | PhoneticUnitType::Conjunct
+            | PhoneticUnitType::RephOverConsonant
+            | PhoneticUnitType::ChandrabinduWithConsonant => !unit.text.ends_with(",,"),
+            PhoneticUnitType::SpecialForm => self.is_special_cluster(&unit.text),
+            _ => false,
+        });
+        
+        if nuclei == 0 && has_inherent { 1 } else { nuclei }
+    }
+    
+    /// The form a vowel is written in, given whether a consonant precedes it
+    ///
+    /// A vowel after a consonant is written as its sign when it has one;
+    /// otherwise, and for a vowel that isn't mapped, the full letter is written.
+    pub fn vowel_form_in_context(&self, prev_is_consonant: bool, roman_vowel: &str) -> VowelForm {
+        match self.vowels.get(roman_vowel) {
+            Some(vowel) if prev_is_consonant && vowel.dependent.is_some() => VowelForm::Dependent,
+            _ => VowelForm::Independent,
+        }
+    }
+    
+    /// Split a word into its syllables, as [`Transliterator::with_syllable_markers`] does
+    ///
+    /// Hyphens are left out of the syllables' Bengali text.
+    pub fn syllables(&self, word: &str) -> Vec<Syllable> {
+        let word = self.prepare_input(word.to_string());
+        let (units, rendered): (Vec<PhoneticUnit>, Vec<String>) =
+            self.rendered_units(&word).into_iter().unzip();
+        let (_, hyphens) = split_hyphens(&word);
+        let starts = self.syllable_starts(&units, &hyphens);
+        
+        let mut syllables: Vec<Syllable> = Vec::new();
+        for ((unit, bengali), start) in units.into_iter().zip(rendered).zip(starts) {
+            match syllables.last_mut() {
+                Some(syllable) if !start => {
+                    syllable.units.push(unit);
+                    syllable.bengali.push_str(&bengali);
+                },
+                _ => syllables.push(Syllable { units: vec![unit], roman: String::new(), bengali }),
+            }
+        }
+        
+        // Unit texts can differ from the input (an implicit conjunct gains a
+        // hasant), so each syllable takes the input up to where the next starts
+        let (base, _) = split_hyphens(&word);
+        let mut ends: Vec<usize> = syllables.iter().skip(1).map(|syllable| syllable.units[0].position).collect();
+        ends.push(base.len());
+        for (syllable, end) in syllables.iter_mut().zip(ends) {
+            syllable.roman = base[syllable.units[0].position..end].to_string();
+        }
+        
+        syllables
+    }
+    
+    /// Pair the Roman text of each syllable of a word with its Bengali
+    ///
+    /// A shorthand for [`Transliterator::syllables`], e.g. for highlighting
+    /// the input and output together syllable by syllable.
+    pub fn syllable_pairs(&self, word: &str) -> Vec<(String, String)> {
+        self.syllables(word).into_iter()
+            .map(|syllable| (syllable.roman, syllable.bengali))
+            .collect()
+    }
+    
+    /// The phonetic units of a word with its hyphens removed, tokenizing each
+    /// fragment between the `hyphens` offsets on its own
+    fn tokenize_compound(&self, base: &str, hyphens: &[usize]) -> Vec<PhoneticUnit> {
+        if hyphens.is_empty() {
+            return self.tokenizer.tokenize_word(base);
+        }
+        
+        let mut units = Vec::new();
+        let mut start = 0;
+        for end in hyphens.iter().copied().chain(core::iter::once(base.len())) {
+            units.extend(self.tokenizer.tokenize_word(&base[start..end]).into_iter().map(|mut unit| {
+                unit.position += start;
+                unit
+            }));
+            start = end;
+        }
+        
+        units
+    }
+    
+    /// The phonetic units of a word, each with the Bengali it is written as
+    fn rendered_units(&self, word: &str) -> Vec<(PhoneticUnit, String)> {
+        let mut annotations = Vec::new();
+        self.transliterate_word_into(word, &mut String::new(), Some(&mut annotations), None, self.final_consonant);
+        
+        let (base, hyphens) = split_hyphens(word);
+        let units = self.tokenize_compound(&base, &hyphens);
+        
+        // Hyphens and syllable markers have annotations of their own, without a unit
+        let mut rendered = vec![String::new(); units.len()];
+        for annotation in annotations {
+            if let Some(index) = annotation.unit_index {
+                rendered[index] = annotation.bengali;
+            }
+        }
+        
+        units.into_iter().zip(rendered).collect()
+    }
+    
+    /// Whether a special form is a consonant cluster written with a hasant, like ক্ষ
+    fn is_special_cluster(&self, text: &str) -> bool {
+        let starts_with_consonant = text.get(..1)
+            .is_some_and(|first| self.consonants.contains_key(first));
+        starts_with_consonant && self.special_form(text)
+            .is_some_and(|bengali| bengali.contains('্'))
+    }
+    
+    /// For each unit of a word, whether a new syllable starts at it
+    ///
+    /// A syllable also starts after each of the `hyphens` of a compound.
+    fn syllable_starts(&self, units: &[PhoneticUnit], hyphens: &[usize]) -> Vec<bool> {
+        let mut starts = Vec::with_capacity(units.len());
+        let mut has_nucleus = false;
+        let mut after_onset = false;
+        
+        for (index, unit) in units.iter().enumerate() {
+            let next_is_vowel = units.get(index + 1).is_some_and(|next| !hyphens.contains(&next.position) && matches!(next.unit_type,
+                PhoneticUnitType::Vowel | PhoneticUnitType::TerminatingVowel | PhoneticUnitType::ChandrabinduWithVowel));
+            
+            let after_hyphen = index > 0 && hyphens.contains(&unit.position);
+            if after_hyphen {
+                after_onset = false;
+            }
+            
+            let start = after_hyphen || match unit.unit_type {
+                // A bare vowel after an onset is its vowel sign
+                PhoneticUnitType::Vowel
+                | PhoneticUnitType::TerminatingVowel
+                | PhoneticUnitType::ChandrabinduWithVowel => !after_onset,
+                PhoneticUnitType::ConsonantWithVowel
+                | PhoneticUnitType::ConsonantWithTerminator
+                | PhoneticUnitType::ConjunctWithVowel
+                | PhoneticUnitType::ConjunctWithTerminator
+                | PhoneticUnitType::RephOverConsonantWithVowel
+                | PhoneticUnitType::RephOverConsonantWithTerminator
+                | PhoneticUnitType::ChandrabinduWithConsonantAndVowel => true,
+                // Consonants without a vowel start a syllable only as the onset of a following vowel
+                PhoneticUnitType::Consonant
+                | PhoneticUnitType::Conjunct
+                | PhoneticUnitType::RephOverConsonant
+                | PhoneticUnitType::ChandrabinduWithConsonant => next_is_vowel,
+                PhoneticUnitType::SpecialForm => next_is_vowel && self.is_special_cluster(&unit.text),
+                _ => false,
+            };
+            
+            starts.push(start && has_nucleus);
+            after_onset = start && !unit_has_vowel(&unit.unit_type);
+            has_nucleus = has_nucleus || unit_has_vowel(&unit.unit_type) || after_onset;
+        }
+        
+        starts
+    }
+    
+    /// Sanitize the input text, ensuring it contains only allowed characters
+    pub fn sanitize(&self, text: &str) -> SanitizeResult {
+        self.sanitizer.sanitize(text)
+    }
+    
+    /// Transliterate Roman text to Bengali, cleaning invalid characters instead of returning an error
+    pub fn transliterate_lenient(&self, text: &str) -> String {
+        // Empty and whitespace-only input is returned unchanged, including
+        // whitespace the sanitizer doesn't accept, such as tabs and newlines
+        if text.trim().is_empty() {
+            return text.to_string();
+        }
+        
+        // Clean the input by removing invalid characters
+        let cleaned = self.sanitizer.clean(text);
+        
+        if self.passthrough {
+            // Return the cleaned input as typed, before case folding
+            self.tokenize_words_only(&self.tokenizer.tokenize_text(&self.prepare_input(cleaned.clone())));
+            return cleaned;
+        }
+        
+        // Process the cleaned text using the tokenizer
+        let cleaned = self.prepare_input(cleaned);
+        let tokens = self.tokenizer.tokenize_text(&cleaned);
+        
+        // Process each token based on its type
+        let mut result = String::new();
+        
+        for token in &tokens {
+            self.transliterate_token_into(token, &mut result);
+        }
+        
+        result
+    }
+    
+    /// Whether every fragment of the input has a Bengali mapping
+    ///
+    /// See [`Transliterator::unmappable_fragments`].
+    pub fn is_fully_mappable(&self, text: &str) -> bool {
+        self.unmappable_fragments(text).is_empty()
+    }
+    
+    /// Every fragment of the input without a Bengali mapping, with its byte position
+    ///
+    /// These are the characters the sanitizer rejects or, if there are none,
+    /// the phonetic units of type `Unknown` and any punctuation that is not
+    /// written in Bengali text (e.g. `#`), in input order. With case folding
+    /// or a scheme other than Avro, positions refer to the converted input.
+    pub fn unmappable_fragments(&self, text: &str) -> Vec<(String, usize)> {
+        let invalid = self.sanitizer.validate(text);
+        if !invalid.is_empty() {
+            return invalid.into_iter()
+                .map(|error| (error.character.to_string(), error.position))
+                .collect();
+        }
+        
+        let prepared = self.prepare_input(text.to_string());
+        let mut fragments = Vec::new();
+        
+        for token in self.tokenizer.token_iter(&prepared) {
+            if matches!(token.token_type, TokenType::Punctuation | TokenType::Symbol) {
+                // A run such as "..." is as mappable as its marks
+                let mappable = self.symbols.contains_key(token.content.as_str()) ||
+                    token.content.chars().all(|c| SHARED_PUNCTUATION.contains(c) || self.symbols.contains_key(c.encode_utf8(&mut [0; 4])));
+                if !mappable {
+                    fragments.push((token.content, token.position));
+                }
+                continue;
+            }
+            if token.token_type != TokenType::Word || self.dictionary_entry(&token.content).is_some() {
+                continue;
+            }
+            
+            // Words are tokenized without their hyphens, as in transliteration
+            let (base, hyphens) = split_hyphens(&token.content);
+            for unit in self.tokenize_compound(&base, &hyphens) {
+                if unit.unit_type == PhoneticUnitType::Unknown {
+                    let hyphens_before = hyphens.iter().filter(|&&position| position <= unit.position).count();
+                    fragments.push((unit.text, token.position + unit.position + hyphens_before));
+                }
+            }
+        }
+        
+        fragments
+    }
+    
+    /// Transliterate Roman text to Bengali, listing anything that was not converted cleanly
+    ///
+    /// The output is the same as [`Transliterator::transliterate`]. Fragments
+    /// from [`Transliterator::unmappable_fragments`] are reported as
+    /// [`WarningKind::UnknownFragment`], and units that fell back to their
+    /// Roman text as [`WarningKind::Fallback`], in input order.
+    pub fn transliterate_with_warnings(&self, text: &str) -> (String, Vec<Warning>) {
+        let mut warnings: Vec<Warning> = self.unmappable_fragments(text).into_iter()
+            .map(|(fragment, position)| Warning { kind: WarningKind::UnknownFragment, fragment, position })
+            .collect();
+        
+        // Invalid input is returned unchanged, so nothing else is rendered
+        if self.sanitizer.validate(text).is_empty() {
+            let prepared = self.prepare_input(text.to_string());
+            
+            for token in self.tokenizer.token_iter(&prepared) {
+                if token.token_type != TokenType::Word || self.dictionary_entry(&token.content).is_some() {
+                    continue;
+                }
+                
+                let (_, hyphens) = split_hyphens(&token.content);
+                for (unit, bengali) in self.rendered_units(&token.content) {
+                    if unit.unit_type != PhoneticUnitType::Unknown && bengali.bytes().any(|b| b.is_ascii_alphabetic()) {
+                        let hyphens_before = hyphens.iter().filter(|&&position| position <= unit.position).count();
+                        warnings.push(Warning {
+                            kind: WarningKind::Fallback,
+                            fragment: unit.text,
+                            position: token.position + unit.position + hyphens_before,
+                        });
+                    }
+                }
+            }
+            
+            warnings.sort_by_key(|warning| warning.position);
+        }
+        
+        (self.transliterate(text), warnings)
+    }
+    
+    /// Transliterate Roman text to Bengali, failing on anything without a mapping
+    ///
+    /// Unlike [`Transliterator::transliterate`], nothing is passed through: the
+    /// error lists every fragment from [`Transliterator::unmappable_fragments`].
+    pub fn transliterate_strict(&self, text: &str) -> Result<String, UnmappableError> {
+        let fragments = self.unmappable_fragments(text);
+        if !fragments.is_empty() {
+            return Err(UnmappableError { fragments });
+        }
+        
+        Ok(self.transliterate(text))
+    }
+    
+    /// Transliterate each text strictly, keeping a result per text
+    ///
+    /// The results are in input order, as [`Transliterator::transliterate_strict`]
+    /// gives them, so a failing text doesn't stop the rest of the batch.
+    pub fn batch_try_transliterate(&self, texts: &[&str]) -> Vec<Result<String, UnmappableError>> {
+        texts.iter().map(|text| self.transliterate_strict(text)).collect()
+    }
+    
+    /// Truncate Bengali text to at most `max_clusters` orthographic clusters
+    ///
+    /// A cluster is a grapheme cluster, with clusters joined by a hasant kept
+    /// together, so a conjunct, its vowel sign and any chandrabindu are never
+    /// split. Unlike [`Transliterator::transliterate_capped`], this works on
+    /// text that has already been transliterated.
+    pub fn truncate_clusters(&self, bengali: &str, max_clusters: usize) -> String {
+        let mut clusters = 0;
+        let mut end = 0;
+        
+        for (start, grapheme) in bengali.grapheme_indices(true) {
+            // A grapheme after a hasant continues the same cluster
+            if !ends_with_virama(&bengali[..start]) {
+                if clusters == max_clusters {
+                    break;
+                }
+                clusters += 1;
+            }
+            end = start + grapheme.len();
+        }
+        
+        bengali[..end].to_string()
+    }
+    
+    /// Transliterate the part of `full` within a byte range, e.g. the span of an edit
+    ///
+    /// A word's output can't be split at an arbitrary Roman position, since
+    /// letters on either side of the range may join into a conjunct. The range
+    /// is therefore widened to the whitespace around the words it touches, and
+    /// the output covers those words only. The range is clamped to the text
+    /// and widened to character boundaries.
+    pub fn transliterate_range(&self, full: &str, range: Range<usize>) -> String {
+        let mut start = range.start.min(full.len());
+        let mut end = range.end.clamp(start, full.len());
+        while !full.is_char_boundary(start) {
+            start -= 1;
+        }
+        while !full.is_char_boundary(end) {
+            end += 1;
+        }
+        
+        let start = full[..start].rfind(char::is_whitespace)
+            .map_or(0, |i| i + full[i..].chars().next().map_or(0, char::len_utf8));
+        let end = full[end..].find(char::is_whitespace).map_or(full.len(), |i| end + i);
+        
+        self.transliterate(&full[start..end])
+    }
+    
+    /// Transliterate Roman text to Bengali, with the output capped at `max_bytes`
+    ///
+    /// Returns the output and whether it was truncated. The output is cut
+    /// between grapheme clusters and never inside a cluster joined by a hasant,
+    /// so a conjunct is either kept whole or dropped. Output that fits is the
+    /// same as [`Transliterator::transliterate`].
+    pub fn transliterate_capped(&self, text: &str, max_bytes: usize) -> (String, bool) {
+        let mut output = String::with_capacity(text.len() * 3);
+        self.transliterate_tokens_into(text, &mut output, |token, out| self.transliterate_token_into(token, out));
+        
+        if output.len() <= max_bytes {
+            return (output, false);
+        }
+        
+        // End of the last complete cluster, where the output can be cut
+        let mut safe_len = 0;
+        for (start, _) in output.grapheme_indices(true) {
+            if start > max_bytes {
+                break;
+            }
+            // A grapheme after a hasant (e.g. a bo-fola) joins the cluster before it
+            if !ends_with_virama(&output[..start]) {
+                safe_len = start;
+            }
+        }
+        
+        output.truncate(safe_len);
+        (output, true)
     }
     
-    /// Transliterate Roman text to Bengali, cleaning invalid characters instead of returning an error
-    pub fn transliterate_lenient(&self, text: &str) -> String {
-        // Clean the input by removing invalid characters
-        let cleaned = self.sanitizer.clean(text);
+    /// Transliterate text and report what each input fragment produced
+    ///
+    /// Words are broken down per phonetic unit, while whitespace, punctuation,
+    /// symbols and numbers are reported as one annotation per token (with
+    /// `Symbol` or `Numeral` as their unit type), as are words taken from the
+    /// dictionary (as `SpecialForm`) and words kept as typed with
+    /// [`Script::Latin`] (as `Unknown`). Text written between the tokens, such as
+    /// typographic marks and interlinear brackets, is reported as a `Symbol`
+    /// without Roman text. Concatenating the `bengali` fields yields the same
+    /// output as [`Transliterator::transliterate`]. In passthrough mode the
+    /// whole text is a single `Unknown` annotation.
+    pub fn annotate(&self, text: &str) -> Vec<Annotation> {
+        let mut annotations = Vec::new();
         
-        // Process the cleaned text using the tokenizer
-        let tokens = self.tokenizer.tokenize_text(&cleaned);
+        if text.is_empty() {
+            return annotations;
+        }
         
-        // Process each token based on its type
-        let mut result = String::new();
+        if !self.collapse_whitespace && self.sanitize(text).is_err() {
+            // Mirror `transliterate`, which returns invalid input unchanged
+            annotations.push(Annotation {
+                roman: text.to_string(),
+                bengali: text.to_string(),
+                unit_type: PhoneticUnitType::Unknown,
+                unit_index: None,
+            });
+            return annotations;
+        }
+        
+        if self.passthrough {
+            let mut output = String::with_capacity(text.len());
+            self.transliterate_tokens_into(text, &mut output, |_, _| {});
+            annotations.push(Annotation {
+                roman: text.to_string(),
+                bengali: output,
+                unit_type: PhoneticUnitType::Unknown,
+                unit_index: None,
+            });
+            return annotations;
+        }
         
-        for token in tokens {
+        let mut output = String::with_capacity(text.len() * 3);
+        // End of the output covered by the annotations so far
+        let mut annotated = 0;
+        
+        self.transliterate_tokens_into(text, &mut output, |token, out| {
+            push_unannotated(&out[annotated..], &mut annotations);
+            let start = out.len();
+            
             match token.token_type {
-                TokenType::Word => {
-                    result.push_str(&self.transliterate_word(&token.content));
+                TokenType::Word if self.letter_script != Script::Latin && self.dictionary_entry(&token.content).is_none() => {
+                    self.transliterate_word_into(&token.content, out, Some(&mut annotations), None, self.final_consonant);
                 },
-                TokenType::Whitespace => {
-                    result.push_str(&token.content);
+                _ => {
+                    let unit_type = match token.token_type {
+                        TokenType::Word if self.letter_script == Script::Latin => PhoneticUnitType::Unknown,
+                        TokenType::Word => PhoneticUnitType::SpecialForm,
+                        TokenType::Number => PhoneticUnitType::Numeral,
+                        _ => PhoneticUnitType::Symbol,
+                    };
+                    
+                    self.transliterate_token_into(token, out);
+                    
+                    annotations.push(Annotation {
+                        bengali: out[start..].to_string(),
+                        roman: token.content.clone(),
+                        unit_type,
+                        unit_index: None,
+                    });
                 },
-                TokenType::Punctuation => {
+            }
+            
+            annotated = out.len();
+        });
+        push_unannotated(&output[annotated..], &mut annotations);
+        
+        annotations
+    }
+    
+    /// Transliterate text that may span several lines, with a map from output
+    /// positions back to input lines and columns
+    ///
+    /// Each line is transliterated as [`Transliterator::transliterate`] would,
+    /// and the newlines are kept. Columns are counted after case folding and
+    /// scheme conversion, which for the default scheme match the input.
+    pub fn transliterate_with_source_map(&self, text: &str) -> (String, SourceMap) {
+        let mut output = String::with_capacity(text.len() * 3);
+        let mut map = SourceMap::default();
+        let mut line_output = String::new();
+        
+        // Line and column of the end of the previous line
+        let mut line_end = None;
+        
+        for (index, line) in text.split('\n').enumerate() {
+            // A newline maps to the end of the line it ends
+            if let Some((line_number, column)) = line_end {
+                map.segments.push((output.len(), line_number, column));
+                output.push('\n');
+            }
+            let (line, carriage_return) = match line.strip_suffix('\r') {
+                Some(line) => (line, "\r"),
+                None => (line, ""),
+            };
+            
+            let line_number = index + 1;
+            map.segments.push((output.len(), line_number, 1));
+            
+            let mut starts = Vec::new();
+            self.transliterate_tokens_into(line, &mut line_output, |token, out| {
+                starts.push((out.len(), token.position));
+                self.transliterate_token_into(token, out);
+            });
+            
+            let prepared = self.prepare_input(line.to_string());
+            for (start, position) in starts {
+                let column = prepared.get(..position).map_or(1, |before| before.chars().count() + 1);
+                map.segments.push((output.len() + start, line_number, column));
+            }
+            
+            output.push_str(&line_output);
+            output.push_str(carriage_return);
+            line_end = Some((line_number, line.chars().count() + 1));
+        }
+        
+        (output, map)
+    }
+    
+    /// Transliterate text and pair each token with its Bengali output
+    ///
+    /// Whitespace and most punctuation are paired with themselves; converted
+    /// punctuation, such as '.' and the dari (।), is paired with its conversion.
+    /// Concatenating either side of the pairs yields the input and the output
+    /// of [`Transliterator::transliterate`] respectively, with case folding and
+    /// scheme conversion already applied to the input.
+    pub fn transliterate_pairs(&self, text: &str) -> Vec<(String, String)> {
+        let sanitized = match self.sanitize(text) {
+            Ok(sanitized) => self.prepare_input(sanitized),
+            // Mirror `transliterate`, which returns invalid input unchanged
+            Err(_) => return vec![(text.to_string(), text.to_string())],
+        };
+        
+        if self.passthrough {
+            self.tokenize_words_only(&self.tokenizer.tokenize_text(&sanitized));
+            return vec![(text.to_string(), text.to_string())];
+        }
+        
+        self.tokenizer.token_iter(&sanitized).map(|token| {
+            let mut bengali = String::new();
+            self.transliterate_token_into(&token, &mut bengali);
+            (token.content, bengali)
+        }).collect()
+    }
+    
+    /// Transliterate a single token from the text tokenizer, appending to `result`
+    pub(crate) fn transliterate_token_into(&self, token: &Token, result: &mut String) {
+        self.transliterate_token_with_policy(token, result, self.final_consonant);
+    }
+    
+    /// Transliterate a single token as `transliterate_token_into` does, writing
+    /// word-final consonants by `final_consonant`
+    fn transliterate_token_with_policy(&self, token: &Token, result: &mut String, final_consonant: FinalConsonantPolicy) {
+        match token.token_type {
+            TokenType::Word | TokenType::Number if self.letter_script == Script::Latin => {
+                result.push_str(&token.content);
+            },
+            TokenType::Word => {
+                match self.dictionary_entry(&token.content) {
+                    Some(entry) => result.push_str(entry),
+                    None => self.transliterate_word_into(&token.content, result, None, None, final_consonant),
+                }
+            },
+            TokenType::Whitespace | TokenType::Foreign => {
+                result.push_str(&token.content);
+            },
+            TokenType::Punctuation => {
+                // A run of marks that typography didn't replace, such as "...",
+                // is converted mark by mark as if typed apart
+                for (i, c) in token.content.char_indices() {
+                    let mark = &token.content[i..i + c.len_utf8()];
+                    
                     // For most punctuation, keep it as is
                     // However, some punctuation might need to be converted
-                    if let Some(bengali_symbol) = self.symbols.get(token.content.as_str()) {
+                    if !self.punctuation_conversion || (mark == "." && !self.dari_conversion) {
+                        result.push_str(mark);
+                    } else if let Some(bengali_symbol) = self.symbols.get(mark) {
                         result.push_str(bengali_symbol);
                     } else {
-                        result.push_str(&token.content);
-                    }
-                },
-                TokenType::Number => {
-                    // Convert numbers to Bengali numerals if applicable
-                    let mut numeral_result = String::new();
-                    let mut converted = false;
-                    
-                    for digit in token.content.chars() {
-                        let digit_str = digit.to_string();
-                        if let Some(bengali_digit) = self.numerals.get(digit_str.as_str()) {
-                            numeral_result.push_str(bengali_digit);
-                            converted = true;
-                        } else {
-                            numeral_result.push(digit);
-                        }
-                    }
-                    
-                    if converted {
-                        result.push_str(&numeral_result);
-                    } else {
-                        result.push_str(&token.content);
+                        result.push_str(mark);
                     }
-                },
-                TokenType::Symbol => {
-                    // Convert symbols if applicable
-                    if let Some(bengali_symbol) = self.symbols.get(token.content.as_str()) {
-                        result.push_str(bengali_symbol);
+                }
+            },
+            TokenType::Number => {
+                // Convert numbers to Bengali numerals if applicable,
+                // keeping any character without a numeral mapping as is
+                let mut digit_buffer = [0u8; 4];
+                
+                for digit in token.content.chars() {
+                    let digit_str: &str = digit.encode_utf8(&mut digit_buffer);
+                    if let Some(bengali_digit) = self.numerals.get(digit_str) {
+                        result.push_str(bengali_digit);
                     } else {
-                        result.push_str(&token.content);
+                        result.push(digit);
                     }
-                },
-            }
+                }
+            },
+            TokenType::Symbol => {
+                // Convert symbols if applicable
+                if let Some(bengali_symbol) = self.symbols.get(token.content.as_str()) {
+                    result.push_str(bengali_symbol);
+                } else {
+                    result.push_str(&token.content);
+                }
+            },
+        }
+    }
+    
+    /// Append the sign of a vowel that follows a consonant
+    ///
+    /// The terminating 'o' is the inherent vowel and adds no sign, so a
+    /// consonant it closes is rendered bare, never with a hasant.
+    fn push_vowel_sign(&self, result: &mut String, vowel_text: &str) {
+        match self.vowels.get(vowel_text) {
+            Some(vowel) => {
+                if let Some(dependent) = &vowel.dependent {
+                    result.push_str(dependent);
+                }
+            },
+            // Vowel not recognized, just append it
+            None => result.push_str(vowel_text),
         }
-        
-        result
     }
     
-    /// Transliterate a single word from Roman to Bengali
-    fn transliterate_word(&self, word: &str) -> String {
-        // Tokenize the word into phonetic units
-        let phonetic_units = self.tokenizer.tokenize_word(word);
+    /// Transliterate a single word from Roman to Bengali, appending to `result`
+    ///
+    /// When `annotations` is given, each unit's contribution is recorded too,
+    /// and when `unit_hook` is given it may replace that contribution.
+    fn transliterate_word_into(
+        &self,
+        word: &str,
+        result: &mut String,
+        mut annotations: Option<&mut Vec<Annotation>>,
+        mut unit_hook: Option<&mut UnitHook<'_>>,
+        final_consonant: FinalConsonantPolicy,
+    ) {
+        // Hyphenated compounds arrive as a single word. Each fragment is
+        // tokenized on its own, so no cluster spans a hyphen, and the hyphens
+        // are put back where they were typed.
+        let (base, hyphen_offsets) = split_hyphens(word);
+        let phonetic_units = self.tokenize_compound(&base, &hyphen_offsets);
+        let mut hyphens = hyphen_offsets.iter().copied().peekable();
         
-        println!("DEBUG: Transliterating word: {}", word);
+        log::debug!("Transliterating word: {}", word);
         
         // Placeholder implementation - will be expanded later
         // For now, just mark the units in a debug-friendly way
-        let mut result = String::new();
+        let word_start = result.len();
+        let annotations_start = annotations.as_ref().map_or(0, |annotations| annotations.len());
         let mut prev_was_consonant = false;
         let mut prev_was_bengali_consonant = false;
         
-        for unit in phonetic_units {
-            println!("DEBUG: Processing unit '{}' type: {:?}", unit.text, unit.unit_type);
+        // Syllable markers are inserted once the word is complete, so that the
+        // rules looking back at the output only ever see Bengali text
+        let syllable_starts = match self.syllable_marker {
+            Some(_) => self.syllable_starts(&phonetic_units, &hyphen_offsets),
+            None => Vec::new(),
+        };
+        let mut marker_positions = Vec::new();
+        let mut syllable_break = false;
+        
+        // Whether each unit starts with a consonant, which a broken-off cluster can be joined to
+        let consonant_starts: Vec<bool> = phonetic_units.iter().map(|unit| starts_with_consonant(&unit.unit_type)).collect();
+        
+        let last_index = phonetic_units.len().saturating_sub(1);
+        for (index, unit) in phonetic_units.into_iter().enumerate() {
+            log::debug!("Processing unit '{}' type: {:?}", unit.text, unit.unit_type);
+            let next_is_cluster = consonant_starts.get(index + 1).copied().unwrap_or(false);
+            let after_break = core::mem::take(&mut syllable_break);
+            let mut after_hyphen = false;
+            while hyphens.next_if(|&pos| pos <= unit.position).is_some() {
+                push_hyphen(result, annotations.as_deref_mut());
+                after_hyphen = true;
+            }
+            if after_hyphen {
+                // A vowel after the hyphen starts a new fragment
+                prev_was_consonant = false;
+                prev_was_bengali_consonant = false;
+            }
+            
+            let unit_start = result.len();
+            if let Some(marker) = self.syllable_marker {
+                if syllable_starts[index] && !after_hyphen && !ends_with_virama(result) {
+                    marker_positions.push(unit_start);
+                    
+                    if let Some(annotations) = annotations.as_deref_mut() {
+                        annotations.push(Annotation {
+                            roman: String::new(),
+                            bengali: marker.to_string(),
+                            unit_type: PhoneticUnitType::Symbol,
+                            unit_index: None,
+                        });
+                    }
+                }
+            }
             match unit.unit_type {
                 PhoneticUnitType::Consonant => {
                     if let Some(bengali_consonant) = self.consonants.get(unit.text.as_str()) {
@@ -274,18 +2033,18 @@ impl Transliterator {
                     }
                 },
                 PhoneticUnitType::Vowel => {
-                    if let Some(vowel) = self.vowels.get(unit.text.as_str()) {
-                        if prev_was_consonant {
-                            // If preceded by a consonant, use dependent form if available
-                            if let Some(dependent) = &vowel.dependent {
-                                result.push_str(dependent);
-                            } else {
-                                // If no dependent form exists, use independent as fallback
-                                result.push_str(&vowel.independent);
-                            }
-                        } else {
-                            // Use the independent form for standalone vowels
-                            result.push_str(&vowel.independent);
+                    let combination = vowel_vowel_combinations().get(unit.text.as_str())
+                        .filter(|_| !prev_was_consonant && !after_break && ends_with_vowel(&result[word_start..]))
+                        // A hiatus marker ("ki.a") keeps the vowels apart
+                        .filter(|_| !base.get(..unit.position).is_some_and(|before| before.ends_with('.')));
+                    if let Some(combination) = combination {
+                        // After another vowel, as in "ia" (ইয়া)
+                        result.push_str(combination);
+                        prev_was_consonant = false;
+                    } else if let Some(vowel) = self.vowels.get(unit.text.as_str()) {
+                        match (self.vowel_form_in_context(prev_was_consonant, &unit.text), &vowel.dependent) {
+                            (VowelForm::Dependent, Some(dependent)) => result.push_str(dependent),
+                            _ => result.push_str(vowel.independent),
                         }
                         prev_was_consonant = false;
                     } else {
@@ -297,13 +2056,12 @@ impl Transliterator {
                 PhoneticUnitType::TerminatingVowel => {
                     if let Some(vowel) = self.vowels.get(unit.text.as_str()) {
                         if prev_was_consonant {
-                            // If preceded by a consonant, use dependent form if available
-                            if let Some(dependent) = &vowel.dependent {
-                                result.push_str(dependent);
-                            } else {
-                                // If no dependent form exists, use independent as fallback
-                                result.push_str(&vowel.independent);
-                            }
+                            self.push_vowel_sign(result, &unit.text);
+                        } else if after_break || ends_with_vowel(&result[word_start..]) {
+                            // After another vowel 'o' is pronounced, as in "keo" (কেও),
+                            // and so is an emphatic particle, as in "tomar'o" (তোমারও)
+                            let o = self.vowels.get("O").map_or("ও", |o| o.independent);
+                            result.push_str(o);
                         } else {
                             // Use the independent form for standalone terminating vowels
                             result.push_str(&vowel.independent);
@@ -316,65 +2074,45 @@ impl Transliterator {
                     }
                 },
                 PhoneticUnitType::ConsonantWithVowel => {
-                    println!("DEBUG: ConsonantWithVowel: '{}'", unit.text);
-                    // Special case handling for 'chhi' sequence
-                    if unit.text == "hi" && !result.is_empty() && result.ends_with('র') {
-                        // If 'hi' follows a reph, handle differently
-                        if let Some(bengali_consonant) = self.consonants.get("ch") {
-                            // Create consonant with vowel
+                    log::debug!("ConsonantWithVowel: '{}'", unit.text);
+                    // For consonants like "th" we need to check if they exist in our consonant map
+                    // Extract the consonant and vowel parts
+                    if let Some(pos) = find_vowel_position(&unit.text, self.vowels) {
+                        let consonant_part = &unit.text[0..pos];
+                        let vowel_part = &unit.text[pos..];
+                        
+                        log::debug!("Found vowel at position {}, consonant: '{}', vowel: '{}'", 
+                                 pos, consonant_part, vowel_part);
+                        
+                        if let Some(bengali_consonant) = self.consonants.get(consonant_part) {
+                            log::debug!("Found consonant mapping: '{}' -> '{}'", consonant_part, bengali_consonant);
                             result.push_str(bengali_consonant);
-                            if let Some(vowel) = self.vowels.get("i") {
+                            if let Some(vowel) = self.vowels.get(vowel_part) {
+                                log::debug!("Found vowel mapping: '{}' -> independent:'{}', dependent:{:?}", 
+                                         vowel_part, vowel.independent, vowel.dependent);
                                 if let Some(dependent) = &vowel.dependent {
                                     result.push_str(dependent);
                                 } else {
+                                    // Fallback to independent form if dependent not available
                                     result.push_str(&vowel.independent);
                                 }
+                            } else {
+                                log::debug!("Failed to find vowel mapping for: '{}'", vowel_part);
+                                // Vowel part not recognized, just append it
+                                result.push_str(vowel_part);
                             }
                         } else {
-                            // Fallback
+                            log::debug!("Failed to find consonant mapping for: '{}'", consonant_part);
+                            // Consonant not recognized, just use the original text
                             result.push_str(&unit.text);
                         }
                     } else {
-                        // Regular handling of consonant with vowel
-                        // For consonants like "th" we need to check if they exist in our consonant map
-                        // Extract the consonant and vowel parts
-                        if let Some(pos) = find_vowel_position(&unit.text, &self.vowels) {
-                            let consonant_part = &unit.text[0..pos];
-                            let vowel_part = &unit.text[pos..];
-                            
-                            println!("DEBUG: Found vowel at position {}, consonant: '{}', vowel: '{}'", 
-                                     pos, consonant_part, vowel_part);
-                            
-                            if let Some(bengali_consonant) = self.consonants.get(consonant_part) {
-                                println!("DEBUG: Found consonant mapping: '{}' -> '{}'", consonant_part, bengali_consonant);
-                                result.push_str(bengali_consonant);
-                                if let Some(vowel) = self.vowels.get(vowel_part) {
-                                    println!("DEBUG: Found vowel mapping: '{}' -> independent:'{}', dependent:{:?}", 
-                                             vowel_part, vowel.independent, vowel.dependent);
-                                    if let Some(dependent) = &vowel.dependent {
-                                        result.push_str(dependent);
-                                    } else {
-                                        // Fallback to independent form if dependent not available
-                                        result.push_str(&vowel.independent);
-                                    }
-                                } else {
-                                    println!("DEBUG: Failed to find vowel mapping for: '{}'", vowel_part);
-                                    // Vowel part not recognized, just append it
-                                    result.push_str(vowel_part);
-                                }
-                            } else {
-                                println!("DEBUG: Failed to find consonant mapping for: '{}'", consonant_part);
-                                // Consonant not recognized, just use the original text
-                                result.push_str(&unit.text);
-                            }
+                        // No vowel found, treat the whole thing as a consonant
+                        if let Some(bengali_consonant) = self.consonants.get(unit.text.as_str()) {
+                            result.push_str(bengali_consonant);
                         } else {
-                            // No vowel found, treat the whole thing as a consonant
-                            if let Some(bengali_consonant) = self.consonants.get(unit.text.as_str()) {
-                                result.push_str(bengali_consonant);
-                            } else {
-                                // Fallback: keep original text
-                                result.push_str(&unit.text);
-                            }
+                            // Fallback: keep original text
+                            result.push_str(&unit.text);
                         }
                     }
                     prev_was_consonant = false;
@@ -383,7 +2121,7 @@ impl Transliterator {
                     // Process consonant with terminating vowel (like o, O)
                     // For consonants like "th" we need to check if they exist in our consonant map
                     // Extract the consonant and terminator parts
-                    if let Some(pos) = find_vowel_position(&unit.text, &self.vowels) {
+                    if let Some(pos) = find_vowel_position(&unit.text, self.vowels) {
                         let consonant_part = &unit.text[0..pos];
                         let terminator_part = &unit.text[pos..];
                         
@@ -391,21 +2129,7 @@ impl Transliterator {
                             // Add the consonant
                             result.push_str(bengali_consonant);
                             
-                            // Handle the terminator - if it's 'o', it's the inherent vowel in Bengali
-                            // and doesn't need a separate symbol
-                            if terminator_part != "o" {
-                                if let Some(vowel) = self.vowels.get(terminator_part) {
-                                    if let Some(dependent) = &vowel.dependent {
-                                        result.push_str(dependent);
-                                    } else {
-                                        // Fallback to independent form if dependent not available
-                                        result.push_str(&vowel.independent);
-                                    }
-                                } else {
-                                    // Terminator part not recognized, just append it
-                                    result.push_str(terminator_part);
-                                }
-                            }
+                            self.push_vowel_sign(result, terminator_part);
                         } else {
                             // Consonant not recognized, just use the original text
                             result.push_str(&unit.text);
@@ -422,11 +2146,17 @@ impl Transliterator {
                     prev_was_consonant = false;
                 },
                 PhoneticUnitType::ConsonantWithHasant => {
-                    // Special case for explicit hasant
-                    if unit.text == ",," && !result.is_empty() {
+                    // A word-final consonant with its hasant ("k,,"), or a standalone hasant
+                    let consonant = unit.text.strip_suffix(",,").unwrap_or(&unit.text);
+                    if let Some(bengali_consonant) = self.consonants.get(consonant) {
+                        result.push_str(bengali_consonant);
+                        result.push_str(self.virama());
+                    } else if consonant.is_empty() && result.len() > word_start {
                         // Apply hasant to the previous consonant
-                        let hasant = self.diacritics.get(",,").unwrap_or(&"্");
+                        let hasant = self.virama();
                         result.push_str(hasant);
+                        // A vowel can't follow the hasant as a vowel sign
+                        prev_was_consonant = false;
                     } else {
                         result.push_str(&unit.text);
                     }
@@ -434,13 +2164,18 @@ impl Transliterator {
                 PhoneticUnitType::Conjunct => {
                     // Process a conjunct based on the text structure
                     // Parse the text which will be in the format: consonant1,,consonant2,,...
-                    let parts: Vec<&str> = unit.text.split(",,").collect();
+                    // with a trailing ",," at the end of a word marking an explicit hasant
+                    let (conjunct_text, trailing_hasant) = match unit.text.strip_suffix(",,") {
+                        Some(conjunct_text) => (conjunct_text, true),
+                        None => (unit.text.as_str(), false),
+                    };
+                    let parts: Vec<&str> = conjunct_text.split(",,").collect();
                     
                     if parts.len() >= 2 {
                         // Process all parts as a multi-consonant conjunct
                         let mut valid_conjunct = true;
                         let mut conjunct_result = String::new();
-                        let hasant = self.diacritics.get(",,").unwrap_or(&"্");
+                        let hasant = self.virama();
                         
                         // Build the conjunct by applying each consonant with hasant
                         for (i, consonant) in parts.iter().enumerate() {
@@ -466,6 +2201,12 @@ impl Transliterator {
                         
                         if valid_conjunct {
                             result.push_str(&conjunct_result);
+                            if trailing_hasant && next_is_cluster {
+                                // A cluster broken off a longer run, see `with_max_conjunct_length`
+                                result.push_str(VISIBLE_VIRAMA);
+                            } else if trailing_hasant {
+                                result.push_str(hasant);
+                            }
                         } else {
                             // Fallback if any consonant wasn't recognized
                             result.push_str(&unit.text);
@@ -486,14 +2227,14 @@ impl Transliterator {
                         let consonant_parts = &parts[0..parts.len()-1];
                         
                         // Find where the vowel begins in the last part
-                        if let Some(vowel_pos) = find_vowel_position(last_part, &self.vowels) {
+                        if let Some(vowel_pos) = find_vowel_position(last_part, self.vowels) {
                             let last_consonant = &last_part[0..vowel_pos];
                             let vowel_part = &last_part[vowel_pos..];
                             
                             // Build the multi-consonant conjunct
                             let mut valid_conjunct = true;
                             let mut conjunct_result = String::new();
-                            let hasant = self.diacritics.get(",,").unwrap_or(&"্");
+                            let hasant = self.virama();
                             
                             // Add all consonants except the last one with hasant
                             for consonant in consonant_parts.iter() {
@@ -571,7 +2312,7 @@ impl Transliterator {
                             // Build the multi-consonant conjunct
                             let mut valid_conjunct = true;
                             let mut conjunct_result = String::new();
-                            let hasant = self.diacritics.get(",,").unwrap_or(&"্");
+                            let hasant = self.virama();
                             
                             // Add all consonants except the last one with hasant
                             for consonant in consonant_parts.iter() {
@@ -625,12 +2366,12 @@ impl Transliterator {
                 PhoneticUnitType::RephOverConsonant => {
                     // Process reph over consonant (র্ + consonant)
                     // Extract the consonant part (after "rr")
-                    let consonant_text = &unit.text[2..]; // Skip the "rr" prefix
+                    let consonant_text = unit.text.get(2..).unwrap_or_default(); // Skip the "rr" prefix
                     
                     if let Some(bengali_consonant) = self.consonants.get(consonant_text) {
                         // Create reph + consonant (reph comes before consonant in Bengali)
                         // In Bengali, reph is represented as র + hasant (্)
-                        let reph = "র্"; // Fixed Bengali reph
+                        let reph = self.reph_over(bengali_consonant);
                         result.push_str(reph);
                         result.push_str(bengali_consonant);
                     } else {
@@ -649,21 +2390,21 @@ impl Transliterator {
                     let mut consonant_end = unit.text.len();
                     
                     // Find where the vowel begins by looking for the first non-consonant character
-                    for i in consonant_start..unit.text.len() {
-                        let c = &unit.text[i..i+1];
-                        if self.vowels.contains_key(c) {
+                    let mut char_buffer = [0u8; 4];
+                    for (i, c) in unit.text.char_indices().skip_while(|&(i, _)| i < consonant_start) {
+                        if self.vowels.contains_key(&*c.encode_utf8(&mut char_buffer)) {
                             consonant_end = i;
                             break;
                         }
                     }
                     
-                    let consonant_part = &unit.text[consonant_start..consonant_end];
-                    let vowel_part = &unit.text[consonant_end..];
+                    let consonant_part = unit.text.get(consonant_start..consonant_end).unwrap_or_default();
+                    let vowel_part = unit.text.get(consonant_end..).unwrap_or_default();
                     
                     if let Some(bengali_consonant) = self.consonants.get(consonant_part) {
                         if let Some(vowel) = self.vowels.get(vowel_part) {
                             // Create reph + consonant + vowel
-                            let reph = "র্"; // Fixed Bengali reph
+                            let reph = self.reph_over(bengali_consonant);
                             result.push_str(reph);
                             result.push_str(bengali_consonant);
                             
@@ -693,15 +2434,11 @@ impl Transliterator {
                     let mut consonant_end = unit.text.len();
                     
                     // Find where the terminator begins by looking for the terminator character
-                    for i in consonant_start..unit.text.len() {
-                        let c = &unit.text[i..i+1];
-                        if c == "o" {
-                            consonant_end = i;
-                            break;
-                        }
+                    if let Some(i) = unit.text.get(consonant_start..).and_then(|rest| rest.find('o')) {
+                        consonant_end = consonant_start + i;
                     }
                     
-                    let consonant_part = &unit.text[consonant_start..consonant_end];
+                    let consonant_part = unit.text.get(consonant_start..consonant_end).unwrap_or_default();
                     let terminator_part = if consonant_end < unit.text.len() {
                         &unit.text[consonant_end..]
                     } else {
@@ -710,19 +2447,13 @@ impl Transliterator {
                     
                     if let Some(bengali_consonant) = self.consonants.get(consonant_part) {
                         // Create reph + consonant
-                        let reph = "র্"; // Fixed Bengali reph
+                        let reph = self.reph_over(bengali_consonant);
                         result.push_str(reph);
                         result.push_str(bengali_consonant);
                         
                         // Add terminator if present
                         if !terminator_part.is_empty() {
-                            if let Some(vowel) = self.vowels.get(terminator_part) {
-                                if let Some(dependent) = &vowel.dependent {
-                                    result.push_str(dependent);
-                                } else {
-                                    result.push_str(&vowel.independent);
-                                }
-                            }
+                            self.push_vowel_sign(result, terminator_part);
                         }
                     } else {
                         // Consonant part not recognized
@@ -733,7 +2464,7 @@ impl Transliterator {
                     // Special forms with proper text field handling
                     if unit.text == "rr" {
                         // Standalone reph is র্
-                        result.push_str("র্");
+                        result.push_str(self.reph());
                     } else if unit.text == "^" {
                         // Standalone Chandrabindu
                         if let Some(chandrabindu) = self.diacritics.get("^") {
@@ -752,6 +2483,12 @@ impl Transliterator {
                         // Handle Khanda Ta (special form of ta)
                         let khanda_ta = self.special_rules.get("T``").unwrap_or(&"ৎ");
                         result.push_str(khanda_ta);
+                    } else if unit.text == "\\" {
+                        // The reph escape in "r\r" writes nothing
+                    } else if unit.text == "'" {
+                        // A syllable break writes nothing, and a vowel after it is independent
+                        prev_was_consonant = false;
+                        syllable_break = true;
                     } else if unit.text == "ng" {
                         // Handle anusvara (ং)
                         if let Some(anusvara) = self.diacritics.get("ng") {
@@ -761,8 +2498,14 @@ impl Transliterator {
                         }
                     } else {
                         // Try to find in special rules
-                        if let Some(special_bengali) = self.special_rules.get(unit.text.as_str()) {
+                        if let Some(special_bengali) = self.special_form(&unit.text) {
                             result.push_str(special_bengali);
+                            
+                            // Clusters like ক্ষ take a following vowel as a sign
+                            let ends_with_consonant = self.consonants.values()
+                                .any(|consonant| special_bengali.ends_with(consonant));
+                            prev_was_consonant = ends_with_consonant;
+                            prev_was_bengali_consonant = ends_with_consonant;
                         } else {
                             // Fallback: keep original text
                             result.push_str(&unit.text);
@@ -790,21 +2533,23 @@ impl Transliterator {
                         result.push_str(&unit.text);
                     }
                 },
-                PhoneticUnitType::Unknown => {
-                    // Special handling for 'w' as bo-phola when it follows a consonant
-                    if unit.text == "w" && prev_was_bengali_consonant {
-                        // Apply bo-phola (ব-ফলা) to the previous consonant
-                        let hasant = self.diacritics.get(",,").unwrap_or(&"্");
-                        let ba = "ব"; // Bengali letter 'ba' for bo-phola
+                PhoneticUnitType::BoFola => {
+                    // Apply bo-fola (ব-ফলা) to the previous consonant. The result is
+                    // still a consonant cluster, so a following vowel takes its dependent form.
+                    let hasant = self.virama();
+                    let ba = self.consonants.get("b").unwrap_or(&"ব");
+                    if self.bo_fola {
                         result.push_str(hasant);
-                        result.push_str(ba);
-                        prev_was_consonant = false;
-                        prev_was_bengali_consonant = false;
                     }
+                    result.push_str(ba);
+                    prev_was_consonant = true;
+                    prev_was_bengali_consonant = true;
+                },
+                PhoneticUnitType::Unknown => {
                     // Special handling for 'y' as jo-phola when it follows a consonant
-                    else if unit.text == "y" && prev_was_bengali_consonant {
+                    if unit.text == "y" && prev_was_bengali_consonant {
                         // Apply jo-phola (য-ফলা) to the previous consonant
-                        let hasant = self.diacritics.get(",,").unwrap_or(&"্");
+                        let hasant = self.virama();
                         let ya = "য"; // Regular yo (য) for jo-phola
                         result.push_str(hasant);
                         result.push_str(ya);
@@ -812,16 +2557,26 @@ impl Transliterator {
                         prev_was_bengali_consonant = false;
                     }
                     else {
-                        // Keep other unknown units as is
-                        result.push_str(&unit.text);
+                        // Analysis through annotations is not a transliteration
+                        #[cfg(feature = "metrics")]
+                        if annotations.is_none() {
+                            self.stats.record_unknown_fragment();
+                        }
+                        
+                        // Render other unknown units according to the configured policy
+                        match self.unknown_policy {
+                            UnknownPolicy::Passthrough => result.push_str(&unit.text),
+                            UnknownPolicy::Drop => {},
+                            UnknownPolicy::Replace(marker) => result.push(marker),
+                        }
                         prev_was_consonant = false;
                         prev_was_bengali_consonant = false;
                     }
                 },
                 PhoneticUnitType::ChandrabinduWithConsonant => {
                     // Handle consonant with chandrabindu (nasalization)
-                    // Get the base consonant (all characters except the last one)
-                    let consonant_text = &unit.text[0..unit.text.len()-1];
+                    // Get the base consonant (the text without the trailing chandrabindu)
+                    let consonant_text = unit.text.strip_suffix('^').unwrap_or(&unit.text);
                     let chandrabindu = self.diacritics.get("^").unwrap_or(&"ঁ");
                     
                     if let Some(bengali_consonant) = self.consonants.get(consonant_text) {
@@ -835,7 +2590,7 @@ impl Transliterator {
                 PhoneticUnitType::ChandrabinduWithVowel => {
                     // Handle vowel with chandrabindu (nasalization)
                     // Get the base vowel (all characters except the last one)
-                    let vowel_text = &unit.text[0..unit.text.len()-1];
+                    let vowel_text = unit.text.strip_suffix('^').unwrap_or(&unit.text);
                     let chandrabindu = self.diacritics.get("^").unwrap_or(&"ঁ");
                     
                     if let Some(vowel) = self.vowels.get(vowel_text) {
@@ -862,10 +2617,10 @@ impl Transliterator {
                 PhoneticUnitType::ChandrabinduWithConsonantAndVowel => {
                     // Handle consonant + vowel + chandrabindu
                     // Extract the consonant, vowel, and chandrabindu parts
-                    let base_text = &unit.text[0..unit.text.len()-1]; // Text without the chandrabindu
+                    let base_text = unit.text.strip_suffix('^').unwrap_or(&unit.text); // Text without the chandrabindu
                     let chandrabindu = self.diacritics.get("^").unwrap_or(&"ঁ");
                     
-                    if let Some(pos) = find_vowel_position(base_text, &self.vowels) {
+                    if let Some(pos) = find_vowel_position(base_text, self.vowels) {
                         let consonant_part = &base_text[0..pos];
                         let vowel_part = &base_text[pos..];
                         
@@ -902,9 +2657,55 @@ impl Transliterator {
                     prev_was_consonant = false;
                 },
             }
+            
+            if index == 0 && self.initial_ja {
+                if let (Some(ya), Some(ja)) = (self.consonants.get("y"), self.consonants.get("z")) {
+                    if result[unit_start..].starts_with(ya) {
+                        result.replace_range(unit_start..unit_start + ya.len(), ja);
+                    }
+                }
+            }
+            
+            // A word-final bare consonant cluster optionally takes an explicit hasant
+            if index == last_index && final_consonant == FinalConsonantPolicy::Hasant &&
+               matches!(unit.unit_type, PhoneticUnitType::Consonant | PhoneticUnitType::Conjunct | PhoneticUnitType::RephOverConsonant) &&
+               !ends_with_virama(result) {
+                result.push('্');
+            }
+            
+            if let Some(hook) = unit_hook.as_deref_mut() {
+                if let Some(replacement) = hook(&unit, &result[unit_start..]) {
+                    result.replace_range(unit_start.., &replacement);
+                }
+            }
+            
+            if let Some(annotations) = annotations.as_deref_mut() {
+                annotations.push(Annotation {
+                    roman: unit.text.clone(),
+                    bengali: result[unit_start..].to_string(),
+                    unit_type: unit.unit_type.clone(),
+                    unit_index: Some(index),
+                });
+            }
         }
         
-        result
+        for _ in hyphens {
+            push_hyphen(result, annotations.as_deref_mut());
+        }
+        
+        if let Some(marker) = self.syllable_marker {
+            for &position in marker_positions.iter().rev() {
+                result.insert(position, marker);
+            }
+        }
+        
+        if self.explicit_inherent_vowel {
+            let marked = mark_inherent_vowels(&result[word_start..]);
+            if let Some(annotations) = annotations {
+                copy_inherent_vowel_markers(&mut annotations[annotations_start..], &marked);
+            }
+            result.replace_range(word_start.., &marked);
+        }
     }
 }
 
@@ -914,24 +2715,209 @@ impl Default for Transliterator {
     }
 }
 
+// Helper function to append text folded to lowercase as CaseFoldProfile::Simple does
+fn push_folded(text: &str, folded: &mut String) {
+    for (i, c) in text.char_indices() {
+        // Keep 'O' (no lowercase equivalent) with the diphthongs 'OI' and 'OU',
+        // and the 'T' of Khanda Ta (T``)
+        let diphthong = matches!(c, 'I' | 'U') && text[..i].ends_with('O');
+        if c == 'O' || diphthong || (c == 'T' && text[i + 1..].starts_with("``")) {
+            folded.push(c);
+        } else {
+            folded.push(c.to_ascii_lowercase());
+        }
+    }
+}
+
+// Helper function to report output written outside of any token, such as a
+// typographic mark, as a Symbol. Whitespace is written as typed and keeps its
+// Roman text; other marks have none.
+fn push_unannotated(bengali: &str, annotations: &mut Vec<Annotation>) {
+    if !bengali.is_empty() {
+        annotations.push(Annotation {
+            roman: if bengali.trim().is_empty() { bengali.to_string() } else { String::new() },
+            bengali: bengali.to_string(),
+            unit_type: PhoneticUnitType::Symbol,
+            unit_index: None,
+        });
+    }
+}
+
+// Helper function to replace each run of whitespace with a space, or with a
+// newline when `keep_newlines` is set and the run contains one
+fn collapse_whitespace(text: &str, keep_newlines: bool) -> String {
+    let mut collapsed = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    
+    while let Some(c) = chars.next() {
+        if !c.is_whitespace() {
+            collapsed.push(c);
+            continue;
+        }
+        
+        let mut newline = c == '\n';
+        while let Some(next) = chars.next_if(|next| next.is_whitespace()) {
+            newline |= next == '\n';
+        }
+        collapsed.push(if newline && keep_newlines { '\n' } else { ' ' });
+    }
+    
+    collapsed
+}
+
+/// A callback of [`Transliterator::transliterate_with`], given each phonetic
+/// unit and its Bengali
+type UnitHook<'a> = dyn FnMut(&PhoneticUnit, &str) -> Option<String> + 'a;
+
+/// The hasant of [`ViramaStyle::Visible`], kept visible by a zero width non-joiner
+const VISIBLE_VIRAMA: &str = "্\u{200C}";
+
+/// How [`Transliterator::with_explicit_inherent_vowel`] writes the inherent vowel
+const INHERENT_VOWEL_MARKER: &str = "(অ)";
+
+// Helper function to write INHERENT_VOWEL_MARKER after each consonant of a
+// Bengali word that is not followed by a vowel sign or hasant
+fn mark_inherent_vowels(word: &str) -> String {
+    let mut marked = String::with_capacity(word.len() * 2);
+    let mut chars = word.chars().peekable();
+    
+    while let Some(c) = chars.next() {
+        marked.push(c);
+        if !matches!(c, '\u{0995}'..='\u{09B9}' | '\u{09DC}' | '\u{09DD}' | '\u{09DF}') {
+            continue;
+        }
+        
+        // The nukta, chandrabindu and joiners belong to the consonant
+        while let Some(next) = chars.next_if(|&next| matches!(next, '\u{09BC}' | 'ঁ' | '\u{200C}' | '\u{200D}')) {
+            marked.push(next);
+        }
+        if !chars.peek().is_some_and(|&next| matches!(next, '\u{09BE}'..='\u{09CD}' | '\u{09D7}')) {
+            marked.push_str(INHERENT_VOWEL_MARKER);
+        }
+    }
+    
+    marked
+}
+
+// Helper function to add the markers of a word marked by mark_inherent_vowels
+// to the annotations of its units, after the consonant each marker follows
+fn copy_inherent_vowel_markers(annotations: &mut [Annotation], marked: &str) {
+    let mut rest = marked;
+    
+    for annotation in annotations {
+        let mut bengali = String::with_capacity(annotation.bengali.len());
+        for c in annotation.bengali.chars() {
+            bengali.push(c);
+            rest = &rest[c.len_utf8()..];
+            if let Some(after) = rest.strip_prefix(INHERENT_VOWEL_MARKER) {
+                bengali.push_str(INHERENT_VOWEL_MARKER);
+                rest = after;
+            }
+        }
+        annotation.bengali = bengali;
+    }
+}
+
+// Helper function to remove the hyphens from a hyphenated compound, returning
+// the joined word and the byte offsets in it where each hyphen stood
+fn split_hyphens(word: &str) -> (String, Vec<usize>) {
+    let mut base = String::with_capacity(word.len());
+    let mut hyphens = Vec::new();
+    
+    for c in word.chars() {
+        if c == '-' {
+            hyphens.push(base.len());
+        } else {
+            base.push(c);
+        }
+    }
+    
+    (base, hyphens)
+}
+
+// Helper function to emit a hyphen of a hyphenated compound
+fn push_hyphen(result: &mut String, annotations: Option<&mut Vec<Annotation>>) {
+    result.push('-');
+    
+    if let Some(annotations) = annotations {
+        annotations.push(Annotation {
+            roman: "-".to_string(),
+            bengali: "-".to_string(),
+            unit_type: PhoneticUnitType::Symbol,
+            unit_index: None,
+        });
+    }
+}
+
+/// The preferred of several Roman inputs for the same letter: lowercase, then shortest
+fn canonical_roman<'a>(candidates: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    candidates.min_by_key(|roman| (roman.chars().any(|c| c.is_ascii_uppercase()), roman.len(), *roman))
+}
+
+/// Whether a phonetic unit carries a vowel
+fn unit_has_vowel(unit_type: &PhoneticUnitType) -> bool {
+    matches!(unit_type,
+        PhoneticUnitType::Vowel
+        | PhoneticUnitType::TerminatingVowel
+        | PhoneticUnitType::ChandrabinduWithVowel
+        | PhoneticUnitType::ConsonantWithVowel
+        | PhoneticUnitType::ConsonantWithTerminator
+        | PhoneticUnitType::ConjunctWithVowel
+        | PhoneticUnitType::ConjunctWithTerminator
+        | PhoneticUnitType::RephOverConsonantWithVowel
+        | PhoneticUnitType::RephOverConsonantWithTerminator
+        | PhoneticUnitType::ChandrabinduWithConsonantAndVowel)
+}
+
+/// Whether a phonetic unit starts with a consonant or consonant cluster
+fn starts_with_consonant(unit_type: &PhoneticUnitType) -> bool {
+    matches!(unit_type,
+        PhoneticUnitType::Consonant
+        | PhoneticUnitType::ConsonantWithVowel
+        | PhoneticUnitType::ConsonantWithTerminator
+        | PhoneticUnitType::ConsonantWithHasant
+        | PhoneticUnitType::Conjunct
+        | PhoneticUnitType::ConjunctWithVowel
+        | PhoneticUnitType::ConjunctWithTerminator
+        | PhoneticUnitType::ChandrabinduWithConsonant
+        | PhoneticUnitType::ChandrabinduWithConsonantAndVowel)
+}
+
+/// Whether the text ends with a Bengali vowel, independent or dependent
+fn ends_with_vowel(text: &str) -> bool {
+    text.chars().next_back().is_some_and(|c| {
+        ('\u{0985}'..='\u{0994}').contains(&c) || ('\u{09BE}'..='\u{09CC}').contains(&c)
+    })
+}
+
+/// Whether the character is in the Bengali Unicode block
+fn is_bengali_char(c: char) -> bool {
+    ('\u{0980}'..='\u{09FF}').contains(&c)
+}
+
+/// Whether the text ends with a hasant, in any [`ViramaStyle`]
+fn ends_with_virama(text: &str) -> bool {
+    text.trim_end_matches(['\u{200C}', '\u{200D}']).ends_with('্')
+}
+
 // Helper function to find where the vowel part starts in a string
-fn find_vowel_position(text: &str, vowels: &HashMap<&str, BengaliVowel>) -> Option<usize> {
-    println!("DEBUG: Finding vowel position in: '{}'", text);
+fn find_vowel_position(text: &str, vowels: &phf::Map<&'static str, BengaliVowel>) -> Option<usize> {
+    log::debug!("Finding vowel position in: '{}'", text);
     
     // Try longer vowels first
     let mut vowel_patterns: Vec<&&str> = vowels.keys().collect();
     vowel_patterns.sort_by(|a, b| b.len().cmp(&a.len())); // Sort by length, descending
     
-    // Try each position from the start of the string
-    for start_pos in 0..text.len() {
+    // Try each character position from the start of the string
+    for (start_pos, _) in text.char_indices() {
         for &vowel in &vowel_patterns {
-            if start_pos + vowel.len() <= text.len() && &text[start_pos..start_pos + vowel.len()] == *vowel {
-                println!("DEBUG: Found vowel '{}' at position {}", vowel, start_pos);
+            if text[start_pos..].starts_with(vowel) {
+                log::debug!("Found vowel '{}' at position {}", vowel, start_pos);
                 return Some(start_pos);
             }
         }
     }
     
-    println!("DEBUG: No vowel found in '{}'", text);
+    log::debug!("No vowel found in '{}'", text);
     None
 }
\ No newline at end of file
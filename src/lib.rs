@@ -2,34 +2,524 @@
 //!
 //! This library provides a transliteration engine for converting Roman script
 //! to Bengali script, focusing on accuracy and linguistic correctness.
+//!
+//! Without the default `std` feature the crate is `no_std` and only needs
+//! `alloc`; it then provides the definitions and the tokenizer.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
+use std::sync::Arc;
 
 pub mod definitions;
 pub mod engine;
+#[cfg(feature = "std")]
 pub mod wasm;
 
 // Re-export commonly used types for convenience
-pub use engine::{Sanitizer, SanitizeResult};
-pub use engine::{Tokenizer, Token, TokenType, PhoneticUnit, PhoneticUnitType};
+#[cfg(feature = "std")]
+pub use engine::{Sanitizer, SanitizeError, SanitizeResult, CaseFoldProfile, ConjunctProfile, ConjunctType, FinalConsonantPolicy, MappingConflict, NumberFormatOptions, Script, SourceMap, Syllable, TypographyOptions, UnknownPolicy, UnmappableError, ViramaStyle, VowelForm, Warning, WarningKind};
+#[cfg(feature = "std")]
+pub use engine::{Dictionary, DocumentTransliterator};
+#[cfg(feature = "metrics")]
+pub use engine::{Stats, StatsSnapshot};
+pub use definitions::Scheme;
+pub use engine::{ApostrophePolicy, Tokenizer, Token, TokenIter, TokenType, PhoneticUnit, PhoneticUnitType};
+#[cfg(feature = "std")]
 pub use wasm::ObadhaWasm;
 
 /// Main entry point for the Obadh transliteration engine
+#[cfg(feature = "std")]
 pub struct ObadhEngine {
-    transliterator: engine::Transliterator,
+    // Shared with the blocking tasks of `transliterate_async`
+    transliterator: Arc<engine::Transliterator>,
 }
 
+#[cfg(feature = "std")]
 impl ObadhEngine {
     /// Create a new engine with default settings
     pub fn new() -> Self {
         Self {
-            transliterator: engine::Transliterator::new(),
+            transliterator: Arc::new(engine::Transliterator::new()),
         }
     }
     
+    /// Apply a builder to the transliterator
+    ///
+    /// A transliterator still shared with a `transliterate_async` task, whose
+    /// future was dropped before it finished, is copied, with fresh stats.
+    fn configure(mut self, build: impl FnOnce(engine::Transliterator) -> engine::Transliterator) -> Self {
+        self.transliterator = Arc::new(build(Arc::unwrap_or_clone(self.transliterator)));
+        self
+    }
+    
+    /// Enable or disable passthrough (identity) mode
+    ///
+    /// Intended for profiling only: sanitization and tokenization still run,
+    /// but the input text is returned unchanged instead of being transliterated.
+    /// See [`engine::Transliterator::with_passthrough`].
+    pub fn with_passthrough(self, enabled: bool) -> Self {
+        self.configure(|transliterator| transliterator.with_passthrough(enabled))
+    }
+    
+    /// Set how characters without a Bengali mapping are rendered
+    ///
+    /// Defaults to [`UnknownPolicy::Passthrough`], which keeps them unchanged.
+    pub fn with_unknown_policy(self, policy: UnknownPolicy) -> Self {
+        self.configure(|transliterator| transliterator.with_unknown_policy(policy))
+    }
+    
+    /// Set the case folding profile applied to the input
+    ///
+    /// Folding is opt-in because it loses distinctions such as `T` (ট) versus
+    /// `t` (ত). See [`CaseFoldProfile`] for exactly what each profile folds.
+    pub fn with_case_folding(self, profile: CaseFoldProfile) -> Self {
+        self.configure(|transliterator| transliterator.with_case_folding(profile))
+    }
+    
+    /// Set the Romanization scheme the input is written in
+    ///
+    /// See [`engine::Transliterator::with_scheme`].
+    pub fn with_scheme(self, scheme: Scheme) -> Self {
+        self.configure(|transliterator| transliterator.with_scheme(scheme))
+    }
+    
+    /// Enable or disable converting punctuation to its Bengali equivalent
+    ///
+    /// See [`engine::Transliterator::with_punctuation_conversion`].
+    pub fn with_punctuation_conversion(self, enabled: bool) -> Self {
+        self.configure(|transliterator| transliterator.with_punctuation_conversion(enabled))
+    }
+    
+    /// Set the script punctuation is written in, e.g. [`Script::Latin`] to keep '.'
+    ///
+    /// See [`engine::Transliterator::with_punctuation_script`].
+    pub fn with_punctuation_script(self, script: Script) -> Self {
+        self.configure(|transliterator| transliterator.with_punctuation_script(script))
+    }
+    
+    /// Set the script words and numbers are written in
+    ///
+    /// See [`engine::Transliterator::with_letter_script`].
+    pub fn with_letter_script(self, script: Script) -> Self {
+        self.configure(|transliterator| transliterator.with_letter_script(script))
+    }
+    
+    /// Enable or disable converting a sentence-ending '.' to the dari (।)
+    ///
+    /// See [`engine::Transliterator::with_dari_conversion`].
+    pub fn with_dari_conversion(self, enabled: bool) -> Self {
+        self.configure(|transliterator| transliterator.with_dari_conversion(enabled))
+    }
+    
+    /// Set the typographic marks, such as the em dash, substituted for plain punctuation
+    ///
+    /// See [`engine::Transliterator::with_typography`].
+    pub fn with_typography(self, typography: TypographyOptions) -> Self {
+        self.configure(|transliterator| transliterator.with_typography(typography))
+    }
+    
+    /// Enable or disable writing each word as `roman (bengali)`
+    ///
+    /// See [`engine::Transliterator::with_interlinear`].
+    pub fn with_interlinear(self, enabled: bool) -> Self {
+        self.configure(|transliterator| transliterator.with_interlinear(enabled))
+    }
+    
+    /// Enable or disable the reph (র্) over a following consonant
+    ///
+    /// See [`engine::Transliterator::with_reph`].
+    pub fn with_reph(self, enabled: bool) -> Self {
+        self.configure(|transliterator| transliterator.with_reph(enabled))
+    }
+    
+    /// Enable or disable joining a reph over য, য় and র with a ZWJ
+    ///
+    /// See [`engine::Transliterator::with_reph_zwj`].
+    pub fn with_reph_zwj(self, enabled: bool) -> Self {
+        self.configure(|transliterator| transliterator.with_reph_zwj(enabled))
+    }
+    
+    /// Collapse runs of whitespace, including tabs and newlines, into a single space
+    ///
+    /// See [`engine::Transliterator::with_whitespace_collapse`].
+    pub fn with_whitespace_collapse(self, enabled: bool) -> Self {
+        self.configure(|transliterator| transliterator.with_whitespace_collapse(enabled))
+    }
+    
+    /// Keep a newline when collapsing a run of whitespace that contains one
+    ///
+    /// See [`engine::Transliterator::with_newlines_kept`].
+    pub fn with_newlines_kept(self, enabled: bool) -> Self {
+        self.configure(|transliterator| transliterator.with_newlines_kept(enabled))
+    }
+    
+    /// Write a word-initial `y` as য instead of য়, as in some spellings of names
+    ///
+    /// See [`engine::Transliterator::with_initial_ja`].
+    pub fn with_initial_ja(self, enabled: bool) -> Self {
+        self.configure(|transliterator| transliterator.with_initial_ja(enabled))
+    }
+    
+    /// Enable or disable ya-phala (য-ফলা) formation
+    ///
+    /// Disable it to keep literal consonant + `y` sequences, e.g. in names.
+    /// See [`Tokenizer::with_ya_phala`].
+    pub fn with_ya_phala(self, enabled: bool) -> Self {
+        self.configure(|transliterator| transliterator.with_ya_phala(enabled))
+    }
+    
+    /// Read letters without a mapping of their own as Avro does, e.g. `x` as ক্স
+    ///
+    /// See [`Tokenizer::with_ascii_fallback`].
+    pub fn with_ascii_fallback(self, enabled: bool) -> Self {
+        self.configure(|transliterator| transliterator.with_ascii_fallback(enabled))
+    }
+    
+    /// Break runs of more than `max` consonants into separate clusters
+    ///
+    /// See [`Tokenizer::with_max_conjunct_length`].
+    pub fn with_max_conjunct_length(self, max: usize) -> Self {
+        self.configure(|transliterator| transliterator.with_max_conjunct_length(max))
+    }
+    
+    /// Read only uppercase "OI" and "OU" as the diphthongs ঐ and ঔ
+    ///
+    /// See [`Tokenizer::with_diphthong_case_sensitivity`].
+    pub fn with_diphthong_case_sensitivity(self, enabled: bool) -> Self {
+        self.configure(|transliterator| transliterator.with_diphthong_case_sensitivity(enabled))
+    }
+    
+    /// Keep brand names and other terms as typed, e.g. "iPhone"
+    ///
+    /// See [`Tokenizer::with_protected_terms`].
+    pub fn with_protected_terms(self, terms: Vec<String>) -> Self {
+        self.configure(|transliterator| transliterator.with_protected_terms(terms))
+    }
+    
+    /// Match protected terms regardless of case
+    ///
+    /// See [`Tokenizer::with_protected_terms_ignore_case`].
+    pub fn with_protected_terms_ignore_case(self, enabled: bool) -> Self {
+        self.configure(|transliterator| transliterator.with_protected_terms_ignore_case(enabled))
+    }
+    
+    /// Set how an apostrophe inside a word, as in "amar'i", is handled
+    ///
+    /// See [`Tokenizer::with_apostrophe_policy`].
+    pub fn with_apostrophe_policy(self, policy: ApostrophePolicy) -> Self {
+        self.configure(|transliterator| transliterator.with_apostrophe_policy(policy))
+    }
+    
+    /// Enable or disable bo-fola (ব-ফলা) formation
+    ///
+    /// See [`engine::Transliterator::with_bo_fola`].
+    pub fn with_bo_fola(self, enabled: bool) -> Self {
+        self.configure(|transliterator| transliterator.with_bo_fola(enabled))
+    }
+    
+    /// Set how a word-final consonant without a vowel is written
+    ///
+    /// Defaults to [`FinalConsonantPolicy::Inherent`], the standard orthography
+    /// (নাম). [`FinalConsonantPolicy::Hasant`] writes নাম্.
+    pub fn with_final_hasant(self, policy: FinalConsonantPolicy) -> Self {
+        self.configure(|transliterator| transliterator.with_final_hasant(policy))
+    }
+    
+    /// Change the final consonant policy in place, returning the previous one
+    ///
+    /// See [`engine::Transliterator::set_final_hasant`].
+    pub(crate) fn set_final_hasant(&mut self, policy: FinalConsonantPolicy) -> FinalConsonantPolicy {
+        Arc::make_mut(&mut self.transliterator).set_final_hasant(policy)
+    }
+    
+    /// Set the sequence written for a hasant that joins consonants
+    ///
+    /// Defaults to [`ViramaStyle::Standard`], the plain hasant (্).
+    pub fn with_virama_style(self, style: ViramaStyle) -> Self {
+        self.configure(|transliterator| transliterator.with_virama_style(style))
+    }
+    
+    /// Register a special form, such as an archaic ligature, for a Roman sequence
+    ///
+    /// See [`engine::Transliterator::register_special`].
+    pub fn with_special_form(self, components: &str, rendered: &str) -> Self {
+        self.configure(|transliterator| transliterator.with_special_form(components, rendered))
+    }
+    
+    /// Write a marker between the syllables of each word
+    ///
+    /// See [`engine::Transliterator::with_syllable_markers`].
+    pub fn with_syllable_markers(self, marker: Option<char>) -> Self {
+        self.configure(|transliterator| transliterator.with_syllable_markers(marker))
+    }
+    
+    /// Write the inherent vowel visibly after each consonant that carries it, for teaching
+    ///
+    /// See [`engine::Transliterator::with_explicit_inherent_vowel`].
+    pub fn with_explicit_inherent_vowel(self, enabled: bool) -> Self {
+        self.configure(|transliterator| transliterator.with_explicit_inherent_vowel(enabled))
+    }
+    
+    /// Use a dictionary of whole words in place of the rule-based output
+    ///
+    /// With the `builtin-dict` feature, [`Dictionary::builtin`] provides a list of common words.
+    pub fn with_dictionary(self, dictionary: Dictionary) -> Self {
+        self.configure(|transliterator| transliterator.with_dictionary(dictionary))
+    }
+    
+    /// Transliterate Roman text to Bengali with the default settings
+    ///
+    /// See [`transliterate`].
+    pub fn quick(text: &str) -> String {
+        transliterate(text)
+    }
+    
     /// Transliterate Roman text to Bengali
     pub fn transliterate(&self, text: &str) -> String {
         self.transliterator.transliterate(text)
     }
     
+    /// Transcribe Roman text into broad IPA, as a pronunciation hint
+    ///
+    /// See [`engine::Transliterator::to_ipa`].
+    pub fn to_ipa(&self, text: &str) -> String {
+        self.transliterator.to_ipa(text)
+    }
+    
+    /// Transliterate the prose of a Markdown document, keeping its structure
+    ///
+    /// See [`engine::Transliterator::transliterate_markdown`].
+    #[cfg(feature = "markdown")]
+    pub fn transliterate_markdown(&self, md: &str) -> String {
+        self.transliterator.transliterate_markdown(md)
+    }
+    
+    /// Transliterate Roman text to Bengali on tokio's blocking thread pool
+    ///
+    /// A convenience for async code, not asynchronous work: the same CPU-bound
+    /// transliteration runs under `spawn_blocking`, keeping the runtime's
+    /// worker threads free for large inputs. Must be awaited inside a tokio
+    /// runtime.
+    #[cfg(feature = "tokio")]
+    pub async fn transliterate_async(&self, text: String) -> String {
+        let transliterator = Arc::clone(&self.transliterator);
+        tokio::task::spawn_blocking(move || transliterator.transliterate(&text))
+            .await
+            .unwrap_or_else(|error| std::panic::resume_unwind(error.into_panic()))
+    }
+    
+    /// Transliterate Roman text to Bengali with a final consonant policy for this call only
+    ///
+    /// See [`engine::Transliterator::transliterate_with_final_policy`].
+    pub fn transliterate_with_final_policy(&self, text: &str, policy: FinalConsonantPolicy) -> String {
+        self.transliterator.transliterate_with_final_policy(text, policy)
+    }
+    
+    /// Transliterate Roman text to Bengali into a reusable buffer
+    ///
+    /// See [`engine::Transliterator::transliterate_into`].
+    pub fn transliterate_into(&self, text: &str, out: &mut String) {
+        self.transliterator.transliterate_into(text, out)
+    }
+    
+    /// Transliterate text, letting `f` replace the Bengali of each phonetic unit
+    ///
+    /// See [`engine::Transliterator::transliterate_with`].
+    pub fn transliterate_with<F>(&self, text: &str, f: F) -> String
+    where
+        F: FnMut(&PhoneticUnit, &str) -> Option<String>,
+    {
+        self.transliterator.transliterate_with(text, f)
+    }
+    
+    /// Transliterate each text, writing the results to `writer` as a JSON array
+    ///
+    /// Each element has the shape of [`wasm::TransliterationResult`] without
+    /// the token analysis. Elements are written as they are produced, so memory
+    /// use doesn't grow with the number of texts.
+    pub fn batch_transliterate_json_stream<I, S, W>(&self, texts: I, mut writer: W) -> std::io::Result<()>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+        W: std::io::Write,
+    {
+        writer.write_all(b"[")?;
+        
+        for (index, text) in texts.into_iter().enumerate() {
+            if index > 0 {
+                writer.write_all(b",")?;
+            }
+            let result = wasm::TransliterationResult::new(self, text.as_ref(), false);
+            serde_json::to_writer(&mut writer, &result)?;
+        }
+        
+        writer.write_all(b"]")?;
+        writer.flush()
+    }
+    
+    /// The usage counters accumulated since the engine was created or last reset
+    ///
+    /// See [`engine::Transliterator::stats`].
+    #[cfg(feature = "metrics")]
+    pub fn stats(&self) -> StatsSnapshot {
+        self.transliterator.stats()
+    }
+    
+    /// Set the usage counters back to zero
+    #[cfg(feature = "metrics")]
+    pub fn reset_stats(&self) {
+        self.transliterator.reset_stats()
+    }
+    
+    /// Create a transliterator that memoizes words across one document
+    ///
+    /// See [`DocumentTransliterator`].
+    pub fn document_transliterator(&self) -> DocumentTransliterator<'_> {
+        DocumentTransliterator::new(&self.transliterator)
+    }
+    
+    /// Transliterate Roman text to Bengali, with warnings for anything not converted cleanly
+    ///
+    /// See [`engine::Transliterator::transliterate_with_warnings`].
+    pub fn transliterate_with_warnings(&self, text: &str) -> (String, Vec<Warning>) {
+        self.transliterator.transliterate_with_warnings(text)
+    }
+    
+    /// Check custom mappings for keys that would change how existing input is read
+    ///
+    /// See [`engine::Transliterator::validate_mappings`].
+    pub fn validate_mappings(&self, overrides: &std::collections::HashMap<String, String>) -> Vec<MappingConflict> {
+        self.transliterator.validate_mappings(overrides)
+    }
+    
+    /// Transliterate Roman text to Bengali, failing on anything without a mapping
+    ///
+    /// See [`engine::Transliterator::transliterate_strict`].
+    pub fn transliterate_strict(&self, text: &str) -> Result<String, UnmappableError> {
+        self.transliterator.transliterate_strict(text)
+    }
+    
+    /// Transliterate each text strictly, with a result per text
+    ///
+    /// See [`engine::Transliterator::batch_try_transliterate`].
+    pub fn batch_try_transliterate(&self, texts: &[&str]) -> Vec<Result<String, UnmappableError>> {
+        self.transliterator.batch_try_transliterate(texts)
+    }
+    
+    /// Transliterate the words of `full` touched by a byte range
+    ///
+    /// See [`engine::Transliterator::transliterate_range`].
+    pub fn transliterate_range(&self, full: &str, range: std::ops::Range<usize>) -> String {
+        self.transliterator.transliterate_range(full, range)
+    }
+    
+    /// Transliterate Roman text to Bengali, with the output capped at `max_bytes`
+    ///
+    /// See [`engine::Transliterator::transliterate_capped`].
+    pub fn transliterate_capped(&self, text: &str, max_bytes: usize) -> (String, bool) {
+        self.transliterator.transliterate_capped(text, max_bytes)
+    }
+    
+    /// Transliterate text that may span several lines, mapping output positions
+    /// back to input lines and columns
+    ///
+    /// See [`engine::Transliterator::transliterate_with_source_map`].
+    pub fn transliterate_with_source_map(&self, text: &str) -> (String, SourceMap) {
+        self.transliterator.transliterate_with_source_map(text)
+    }
+    
+    /// Transliterate text and pair each token with its Bengali output
+    ///
+    /// See [`engine::Transliterator::transliterate_pairs`].
+    pub fn transliterate_pairs(&self, text: &str) -> Vec<(String, String)> {
+        self.transliterator.transliterate_pairs(text)
+    }
+    
+    /// Truncate Bengali text to at most `max_clusters` orthographic clusters
+    ///
+    /// See [`engine::Transliterator::truncate_clusters`].
+    pub fn truncate_clusters(&self, bengali: &str, max_clusters: usize) -> String {
+        self.transliterator.truncate_clusters(bengali, max_clusters)
+    }
+    
+    /// Format a number with Bengali digits and lakh/crore grouping
+    ///
+    /// See [`engine::Transliterator::format_number`].
+    pub fn format_number(&self, value: f64, opts: NumberFormatOptions) -> String {
+        self.transliterator.format_number(value, opts)
+    }
+    
+    /// Write the ordinal of a number with Bengali digits, e.g. ১ম for 1st
+    ///
+    /// See [`engine::Transliterator::to_bengali_ordinal`].
+    pub fn to_bengali_ordinal(&self, n: u64) -> String {
+        self.transliterator.to_bengali_ordinal(n)
+    }
+    
+    /// The dependent sign of a vowel on a dotted circle, e.g. ◌া for `A`
+    ///
+    /// See [`engine::Transliterator::vowel_sign`].
+    pub fn vowel_sign(&self, roman_vowel: &str) -> Option<String> {
+        self.transliterator.vowel_sign(roman_vowel)
+    }
+    
+    /// The canonical Roman input for a single Bengali orthographic cluster
+    ///
+    /// See [`engine::Transliterator::cluster_to_roman`].
+    pub fn cluster_to_roman(&self, cluster: &str) -> Option<String> {
+        self.transliterator.cluster_to_roman(cluster)
+    }
+    
+    /// Count the pronounced syllables (vowel nuclei) of a word
+    ///
+    /// See [`engine::Transliterator::pronounced_syllable_count`].
+    pub fn pronounced_syllable_count(&self, word: &str) -> usize {
+        self.transliterator.pronounced_syllable_count(word)
+    }
+    
+    /// Whether a vowel is written as a sign or a full letter after the given context
+    ///
+    /// See [`engine::Transliterator::vowel_form_in_context`].
+    pub fn vowel_form_in_context(&self, prev_is_consonant: bool, roman_vowel: &str) -> VowelForm {
+        self.transliterator.vowel_form_in_context(prev_is_consonant, roman_vowel)
+    }
+    
+    /// Split a word into its syllables
+    ///
+    /// See [`engine::Transliterator::syllables`].
+    pub fn syllables(&self, word: &str) -> Vec<Syllable> {
+        self.transliterator.syllables(word)
+    }
+    
+    /// Pair the Roman text of each syllable of a word with its Bengali
+    ///
+    /// See [`engine::Transliterator::syllable_pairs`].
+    pub fn syllable_pairs(&self, word: &str) -> Vec<(String, String)> {
+        self.transliterator.syllable_pairs(word)
+    }
+    
+    /// Count the conjuncts in a word by type
+    ///
+    /// See [`engine::Transliterator::conjunct_profile`].
+    pub fn conjunct_profile(&self, word: &str) -> ConjunctProfile {
+        self.transliterator.conjunct_profile(word)
+    }
+    
+    /// Whether every fragment of the input has a Bengali mapping
+    pub fn is_fully_mappable(&self, text: &str) -> bool {
+        self.transliterator.is_fully_mappable(text)
+    }
+    
+    /// Every fragment of the input without a Bengali mapping, with its byte position
+    ///
+    /// See [`engine::Transliterator::unmappable_fragments`].
+    pub fn unmappable_fragments(&self, text: &str) -> Vec<(String, usize)> {
+        self.transliterator.unmappable_fragments(text)
+    }
+    
     /// Sanitize input text to ensure it contains only valid characters
     pub fn sanitize(&self, text: &str) -> SanitizeResult {
         self.transliterator.sanitize(text)
@@ -40,6 +530,13 @@ impl ObadhEngine {
         self.transliterator.tokenize(text)
     }
     
+    /// Sanitize and tokenize input text without transliterating it
+    ///
+    /// See [`engine::Transliterator::tokenize_only`].
+    pub fn tokenize_only(&self, text: &str) -> Vec<Token> {
+        self.transliterator.tokenize_only(text)
+    }
+    
     /// Tokenize a word into phonetic units for Bengali transliteration
     pub fn tokenize_phonetic(&self, word: &str) -> Vec<PhoneticUnit> {
         self.transliterator.tokenize_phonetic(word)
@@ -51,8 +548,19 @@ impl ObadhEngine {
     }
 }
 
+#[cfg(feature = "std")]
 impl Default for ObadhEngine {
     fn default() -> Self {
         Self::new()
     }
-}
\ No newline at end of file
+}
+
+/// Transliterate Roman text to Bengali with the default settings
+///
+/// The default transliterator is built on the first call and shared by every
+/// later one, so one-off calls don't pay for building the tables each time.
+#[cfg(feature = "std")]
+pub fn transliterate(text: &str) -> String {
+    static DEFAULT: std::sync::OnceLock<engine::Transliterator> = std::sync::OnceLock::new();
+    DEFAULT.get_or_init(engine::Transliterator::new).transliterate(text)
+}
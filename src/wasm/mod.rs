@@ -3,7 +3,7 @@ use serde::{Serialize, Deserialize};
 use serde_wasm_bindgen::{to_value, from_value};
 use web_sys::Performance;
 
-use crate::ObadhEngine;
+use crate::{FinalConsonantPolicy, ObadhEngine};
 
 // Initialize panic hook for better error messages
 #[wasm_bindgen(start)]
@@ -33,6 +33,10 @@ pub struct TransliterationOptions {
     pub debug: bool,
     /// Include token analysis in output
     pub verbose: bool,
+    /// Write a word-final consonant with its inherent vowel, overriding a
+    /// final hasant set with `set_final_hasant` (e.g. for names)
+    #[serde(default)]
+    pub inherent_final: bool,
 }
 
 #[wasm_bindgen]
@@ -42,10 +46,16 @@ impl TransliterationOptions {
         Self {
             debug: false,
             verbose: false,
+            inherent_final: false,
         }
     }
 }
 
+// The result types below are shared with the CLI's JSON output. Fields are
+// serialized in declaration order, so the JSON is byte-stable for a given input.
+// Fields that are `None` are left out, which JavaScript reads as `undefined`
+// just like the `undefined` values serde_wasm_bindgen writes for them.
+
 /// Performance metrics for the transliteration process
 #[derive(Serialize, Deserialize)]
 pub struct PerformanceMetrics {
@@ -61,16 +71,57 @@ pub struct TokenAnalysis {
     pub content: String,
     pub position: usize,
     pub r#type: String, // "type" is a reserved keyword in JS
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub transliterated: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub phonetic_units: Option<Vec<PhoneticUnitInfo>>,
 }
 
+impl TokenAnalysis {
+    /// Analyze tokens, adding the phonetic units of word tokens, and their
+    /// transliteration when `transliterate_words` is set
+    pub fn from_tokens(engine: &ObadhEngine, tokens: &[crate::Token], transliterate_words: bool) -> Vec<Self> {
+        tokens.iter().map(|token| {
+            let mut analysis = TokenAnalysis {
+                content: token.content.clone(),
+                position: token.position,
+                r#type: format!("{:?}", token.token_type),
+                transliterated: None,
+                phonetic_units: None,
+            };
+            
+            // Add phonetic units for Word tokens
+            if let crate::TokenType::Word = token.token_type {
+                let phonetic_units = engine.tokenize_phonetic(&token.content);
+                
+                if !phonetic_units.is_empty() {
+                    analysis.phonetic_units = Some(phonetic_units.into_iter().map(|unit| PhoneticUnitInfo {
+                        has_chandrabindu: unit.has_chandrabindu(),
+                        has_visarga: unit.has_visarga(),
+                        text: unit.text,
+                        position: unit.position,
+                        r#type: format!("{:?}", unit.unit_type),
+                    }).collect());
+                }
+                
+                if transliterate_words {
+                    analysis.transliterated = Some(engine.transliterate(&token.content));
+                }
+            }
+            
+            analysis
+        }).collect()
+    }
+}
+
 /// Information about a phonetic unit
 #[derive(Serialize, Deserialize)]
 pub struct PhoneticUnitInfo {
     pub text: String,
     pub position: usize,
     pub r#type: String, // "type" is a reserved keyword in JS
+    pub has_chandrabindu: bool,
+    pub has_visarga: bool,
 }
 
 /// Complete transliteration result
@@ -78,10 +129,25 @@ pub struct PhoneticUnitInfo {
 pub struct TransliterationResult {
     pub input: String,
     pub output: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub performance: Option<PerformanceMetrics>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub token_analysis: Option<Vec<TokenAnalysis>>,
 }
 
+impl TransliterationResult {
+    /// Transliterate text without performance metrics, adding the token
+    /// analysis when `verbose` is set
+    pub fn new(engine: &ObadhEngine, text: &str, verbose: bool) -> Self {
+        Self {
+            input: text.to_string(),
+            output: engine.transliterate(text),
+            performance: None,
+            token_analysis: verbose.then(|| TokenAnalysis::from_tokens(engine, &engine.tokenize_only(text), true)),
+        }
+    }
+}
+
 /// ObdahWasm is the main WASM interface to the Obadh engine
 #[wasm_bindgen]
 pub struct ObadhaWasm {
@@ -93,24 +159,28 @@ impl ObadhaWasm {
     /// Create a new instance of the Obadh engine
     #[wasm_bindgen(constructor)]
     pub fn new() -> Self {
+        #[cfg(target_arch = "wasm32")]
         web_sys::console::log_1(&"Creating new ObadhaWasm instance...".into());
         Self {
             engine: ObadhEngine::new(),
         }
     }
+    
+    /// Enable or disable writing a hasant on a word-final consonant
+    ///
+    /// Disabled by default. Calls with the `inherent_final` option still write
+    /// the inherent vowel.
+    #[wasm_bindgen]
+    pub fn set_final_hasant(&mut self, enabled: bool) {
+        let policy = if enabled { FinalConsonantPolicy::Hasant } else { FinalConsonantPolicy::Inherent };
+        self.engine.set_final_hasant(policy);
+    }
 
     /// Transliterate text from Roman to Bengali
     #[wasm_bindgen]
     pub fn transliterate(&self, text: &str) -> String {
-        // For empty text, return empty string immediately
-        if text.trim().is_empty() {
-            return String::new();
-        }
-        
-        // Use a try-catch pattern to handle errors gracefully
-        match self.engine.transliterate(text) {
-            output => output,
-        }
+        // The engine returns empty and whitespace-only input unchanged
+        self.engine.transliterate(text)
     }
 
     /// Transliterate with options for debug/verbose output
@@ -118,13 +188,7 @@ impl ObadhaWasm {
     pub fn transliterate_with_options(&self, text: &str, options_js: JsValue) -> Result<JsValue, JsValue> {
         // For empty text, return a basic result immediately
         if text.trim().is_empty() {
-            let empty_result = TransliterationResult {
-                input: String::new(),
-                output: String::new(),
-                performance: None,
-                token_analysis: None,
-            };
-            return Ok(to_value(&empty_result)?);
+            return Ok(to_value(&self.transliterate_result(text, &TransliterationOptions::new()))?);
         }
         
         // Convert JS options to Rust struct
@@ -135,6 +199,72 @@ impl ObadhaWasm {
             }
         };
         
+        let result = self.transliterate_result(text, &options);
+        
+        // Convert to JsValue and return
+        match to_value(&result) {
+            Ok(val) => Ok(val),
+            Err(e) => Err(JsValue::from_str(&format!("Failed to serialize result: {}", e))),
+        }
+    }
+    
+    /// Analyze text into tokens and phonetic units without transliterating it
+    ///
+    /// Returns the same structure as the verbose `token_analysis` output, but skips
+    /// the performance measurements.
+    #[wasm_bindgen]
+    pub fn analyze(&self, text: &str) -> Result<JsValue, JsValue> {
+        to_value(&self.analysis(text))
+            .map_err(|e| JsValue::from_str(&format!("Failed to serialize analysis: {}", e)))
+    }
+    
+    /// The characters the engine accepts as input, for validating input in JavaScript
+    ///
+    /// See [`crate::Sanitizer::default_allowed_chars`].
+    #[wasm_bindgen]
+    pub fn allowed_chars(&self) -> String {
+        crate::Sanitizer::default_allowed_chars().iter().collect()
+    }
+    
+    /// Get version information
+    #[wasm_bindgen]
+    pub fn get_version(&self) -> String {
+        "0.1.0".to_string() // Hardcoding version to avoid env! macro issues
+    }
+}
+
+impl ObadhaWasm {
+    /// Analyze text, as `analyze` does, without converting to a JS value
+    ///
+    /// Words are broken down into phonetic units but not transliterated.
+    pub fn analysis(&self, text: &str) -> Vec<TokenAnalysis> {
+        TokenAnalysis::from_tokens(&self.engine, &self.engine.tokenize_only(text), false)
+    }
+    
+    /// Transliterate with options, as `transliterate_with_options` does, without
+    /// converting to and from JS values
+    ///
+    ///
+    /// The `inherent_final` option writes the inherent final consonant for this
+    /// call only.
+    pub fn transliterate_result(&self, text: &str, options: &TransliterationOptions) -> TransliterationResult {
+        // For empty text, return a basic result immediately
+        if text.trim().is_empty() {
+            return TransliterationResult {
+                input: text.to_string(),
+                output: text.to_string(),
+                performance: None,
+                token_analysis: None,
+            };
+        }
+        
+        let engine = &self.engine;
+        let transliterate = |text: &str| if options.inherent_final {
+            engine.transliterate_with_final_policy(text, FinalConsonantPolicy::Inherent)
+        } else {
+            engine.transliterate(text)
+        };
+        
         // Create result object
         let mut result = TransliterationResult {
             input: text.to_string(),
@@ -147,7 +277,7 @@ impl ObadhaWasm {
         if options.debug || options.verbose {
             // Measure sanitization performance
             let sanitize_start = now();
-            let sanitized = match self.engine.sanitize(text) {
+            let sanitized = match engine.sanitize(text) {
                 Ok(sanitized) => sanitized,
                 Err(_) => text.to_string(),
             };
@@ -155,12 +285,12 @@ impl ObadhaWasm {
             
             // Measure tokenization performance
             let tokenize_start = now();
-            let tokens = self.engine.tokenize(&sanitized);
+            let tokens = engine.tokenize(&sanitized);
             let tokenize_duration = now() - tokenize_start;
             
             // Measure transliteration performance
             let transliterate_start = now();
-            result.output = self.engine.transliterate(&sanitized);
+            result.output = transliterate(&sanitized);
             let transliterate_duration = now() - transliterate_start;
             
             // Calculate total duration
@@ -176,56 +306,13 @@ impl ObadhaWasm {
             
             // Add token analysis if verbose is enabled
             if options.verbose {
-                let mut token_analysis = Vec::new();
-                
-                for token in tokens {
-                    let mut analysis = TokenAnalysis {
-                        content: token.content.clone(),
-                        position: token.position,
-                        r#type: format!("{:?}", token.token_type),
-                        transliterated: None,
-                        phonetic_units: None,
-                    };
-                    
-                    // Add phonetic units for Word tokens
-                    if let crate::TokenType::Word = token.token_type {
-                        let phonetic_units = self.engine.tokenize_phonetic(&token.content);
-                        
-                        if !phonetic_units.is_empty() {
-                            let mut units_info = Vec::new();
-                            
-                            for unit in phonetic_units {
-                                units_info.push(PhoneticUnitInfo {
-                                    text: unit.text.clone(),
-                                    position: unit.position,
-                                    r#type: format!("{:?}", unit.unit_type),
-                                });
-                            }
-                            
-                            analysis.phonetic_units = Some(units_info);
-                        }
-                    }
-                    
-                    token_analysis.push(analysis);
-                }
-                
-                result.token_analysis = Some(token_analysis);
+                result.token_analysis = Some(TokenAnalysis::from_tokens(engine, &tokens, false));
             }
         } else {
             // Simple transliteration without metrics
-            result.output = self.engine.transliterate(text);
+            result.output = transliterate(text);
         }
         
-        // Convert to JsValue and return
-        match to_value(&result) {
-            Ok(val) => Ok(val),
-            Err(e) => Err(JsValue::from_str(&format!("Failed to serialize result: {}", e))),
-        }
+        result
     }
-    
-    /// Get version information
-    #[wasm_bindgen]
-    pub fn get_version(&self) -> String {
-        "0.1.0".to_string() // Hardcoding version to avoid env! macro issues
-    }
-} 
\ No newline at end of file
+}
@@ -0,0 +1,27 @@
+#![cfg(feature = "tokio")]
+
+use obadh_engine::ObadhEngine;
+
+#[tokio::test]
+async fn test_transliterate_async() {
+    let engine = ObadhEngine::new();
+    
+    let output = engine.transliterate_async("ami banglay gan gai".to_string()).await;
+    assert_eq!(output, engine.transliterate("ami banglay gan gai"));
+}
+
+#[tokio::test]
+async fn test_transliterate_async_then_configure() {
+    let engine = ObadhEngine::new();
+    
+    // The first call starts its task and is dropped, so the task may still be
+    // running when the engine is reconfigured. The engine then gets its own
+    // copy of the transliterator instead of waiting for the task.
+    tokio::select! {
+        biased;
+        _ = engine.transliterate_async("ami banglay gan gai ".repeat(2_000)) => {}
+        _ = std::future::ready(()) => {}
+    }
+    let engine = engine.with_passthrough(true);
+    assert_eq!(engine.transliterate_async("ami".to_string()).await, "ami");
+}
@@ -51,3 +51,507 @@ fn test_tokenization() {
     assert_eq!(tokens[3].content, "world");
     assert_eq!(tokens[4].content, "!");
 }
+
+#[test]
+fn test_passthrough_mode() {
+    let engine = ObadhEngine::new().with_passthrough(true);
+    
+    // The input is returned unchanged, including numbers and punctuation
+    assert_eq!(engine.transliterate("ami banglay gan gai."), "ami banglay gan gai.");
+    assert_eq!(engine.transliterate("kk 123"), "kk 123");
+    
+    // Tokenization is still available in passthrough mode
+    let tokens = engine.tokenize("ami bangla");
+    assert_eq!(tokens.len(), 3);
+    
+    // Disabling passthrough restores normal transliteration
+    let engine = engine.with_passthrough(false);
+    assert_eq!(engine.transliterate("lal"), "লাল");
+}
+
+#[test]
+fn test_passthrough_keeps_original_input() {
+    use obadh_engine::{CaseFoldProfile, PhoneticUnitType};
+    use obadh_engine::engine::Transliterator;
+    
+    // The input is returned as typed, not case folded
+    let engine = ObadhEngine::new().with_case_folding(CaseFoldProfile::Simple).with_passthrough(true);
+    assert_eq!(engine.transliterate("Ami BANGLAY gan gai."), "Ami BANGLAY gan gai.");
+    assert_eq!(engine.transliterate_pairs("AMI"), [("AMI".to_string(), "AMI".to_string())]);
+    
+    let transliterator = Transliterator::new().with_case_folding(CaseFoldProfile::Simple).with_passthrough(true);
+    assert_eq!(transliterator.transliterate_lenient("AMI tumi"), "AMI tumi");
+    
+    // Annotations report the whole input unchanged
+    let annotations = transliterator.annotate("Ami 12, KK");
+    assert_eq!(annotations.len(), 1);
+    assert_eq!(annotations[0].roman, "Ami 12, KK");
+    assert_eq!(annotations[0].bengali, "Ami 12, KK");
+    assert_eq!(annotations[0].unit_type, PhoneticUnitType::Unknown);
+}
+
+#[test]
+fn test_unknown_policy() {
+    use obadh_engine::UnknownPolicy;
+    
+    // Without the ASCII fallback 'Q' has no mapping and is tokenized as an unknown unit
+    let engine = ObadhEngine::new().with_ascii_fallback(false);
+    assert_eq!(engine.transliterate("Qal"), "Qআল");
+    
+    let engine = engine.with_unknown_policy(UnknownPolicy::Passthrough);
+    assert_eq!(engine.transliterate("Qal"), "Qআল");
+    
+    let engine = engine.with_unknown_policy(UnknownPolicy::Drop);
+    assert_eq!(engine.transliterate("Qal"), "আল");
+    
+    let engine = engine.with_unknown_policy(UnknownPolicy::Replace('\u{FFFD}'));
+    assert_eq!(engine.transliterate("Qal"), "\u{FFFD}আল");
+    
+    // Mapped input is unaffected by the policy
+    assert_eq!(engine.transliterate("lal"), "লাল");
+}
+
+#[test]
+fn test_ascii_fallback() {
+    // Letters without a mapping of their own are read as in Avro
+    let engine = ObadhEngine::new();
+    assert_eq!(engine.transliterate("X"), "ক্স");
+    assert_eq!(engine.transliterate("Q"), "ক");
+    assert_eq!(engine.transliterate("Kobor"), "কবর");
+    assert_eq!(engine.transliterate("Khobor"), "খবর");
+    // Letters with a meaning of their own keep it
+    assert_eq!(engine.transliterate("NGa"), "ঞা");
+    // 'w' is the bo-fola after a consonant and ও anywhere else
+    assert_eq!(engine.transliterate("wan"), "ওয়ান");
+    assert_eq!(engine.transliterate("W"), "ও");
+    assert_eq!(engine.transliterate("kWa"), "ক্বা");
+    assert_eq!(engine.transliterate("biSw"), "বিশ্ব");
+    
+    let engine = ObadhEngine::new().with_ascii_fallback(false);
+    assert_eq!(engine.transliterate("X"), "X");
+    assert_eq!(engine.transliterate("Qa"), "Qআ");
+    assert_eq!(engine.transliterate("wan"), "wআন");
+}
+
+#[test]
+fn test_ascii_fallback_positions() {
+    // Unit positions refer to the letters as typed, not their fallback spelling
+    let engine = ObadhEngine::new();
+    let positions: Vec<usize> = engine.tokenize_phonetic("xaxa").into_iter().map(|unit| unit.position).collect();
+    assert_eq!(positions, [0, 2]);
+    
+    let pairs = |word: &str| -> Vec<String> { engine.syllable_pairs(word).into_iter().map(|(roman, _)| roman).collect() };
+    assert_eq!(pairs("xaxa"), ["xa", "xa"]);
+    assert_eq!(pairs("xaxaxaxa"), ["xa", "xa", "xa", "xa"]);
+    
+    assert_eq!(engine.unmappable_fragments("xa#"), [("#".to_string(), 2)]);
+    let (_, warnings) = engine.transliterate_with_warnings("ami xa#");
+    assert_eq!(warnings.iter().map(|warning| warning.position).collect::<Vec<_>>(), [6]);
+}
+
+#[test]
+fn test_case_folding() {
+    use obadh_engine::CaseFoldProfile;
+    
+    // By default the scheme is case-sensitive
+    let engine = ObadhEngine::new();
+    assert_ne!(engine.transliterate("Tomar"), engine.transliterate("tomar"));
+    
+    let engine = ObadhEngine::new().with_case_folding(CaseFoldProfile::Simple);
+    assert_eq!(engine.transliterate("Tomar"), engine.transliterate("tomar"));
+    assert_eq!(engine.transliterate("Tomar"), "তমার");
+    assert_eq!(engine.transliterate("Ami"), "আমি");
+    
+    // 'O' and Khanda Ta are kept as typed
+    assert_eq!(engine.transliterate("kO"), "কো");
+    assert_eq!(engine.transliterate("T``"), "ৎ");
+    
+    // So are the uppercase diphthongs, while "oi" and "Oi" are still two vowels
+    assert_eq!(engine.transliterate("OI"), "ঐ");
+    assert_eq!(engine.transliterate("kOI"), "কৈ");
+    assert_eq!(engine.transliterate("bOU"), "বৌ");
+    assert_eq!(engine.transliterate("koi"), "কই");
+    assert_eq!(engine.transliterate("kOi"), "কোই");
+    
+    // Sensitive profile keeps full case sensitivity
+    let engine = engine.with_case_folding(CaseFoldProfile::Sensitive);
+    assert_eq!(engine.transliterate("Tomar"), "টমার");
+}
+
+#[test]
+fn test_hyphenated_compounds() {
+    let engine = ObadhEngine::new();
+    
+    // Compounds whose fragments end in a vowel match the joined form
+    for (hyphenated, joined) in [("kosmo-biggan", "kosmobiggan"), ("ami-tumi", "amitumi")] {
+        let output = engine.transliterate(hyphenated);
+        assert!(output.contains('-'));
+        assert_eq!(output.replace('-', ""), engine.transliterate(joined));
+    }
+    assert_eq!(engine.transliterate("ami-tumi"), "আমি-তুমি");
+    
+    // The hyphen is a cluster boundary and stays where it was typed
+    assert_eq!(engine.transliterate("ek-tara"), "এক-তারা");
+    assert_eq!(engine.transliterate("bak-ta"), "বাক-তা");
+    assert_eq!(engine.transliterate("a-b-c"), "আ-ব-চ");
+    assert_eq!(engine.transliterate("iPhone-e"), "ইফনে-এ");
+    
+    // A vowel after the hyphen is written in full
+    assert_eq!(engine.transliterate("bak-a"), "বাক-আ");
+    
+    let pairs: Vec<String> = engine.syllable_pairs("bak-a").into_iter().map(|(roman, _)| roman).collect();
+    assert_eq!(pairs, ["bak", "a"]);
+}
+
+#[test]
+fn test_vowel_hiatus_marker() {
+    let engine = ObadhEngine::new();
+    
+    // A dot between vowels prevents combination and never appears in the output
+    assert_eq!(engine.transliterate("kOI"), "কৈ");
+    assert_eq!(engine.transliterate("kO.I"), "কোঈ");
+    assert_eq!(engine.transliterate("O.U"), "ওঊ");
+    assert_eq!(engine.transliterate("ki.a"), "কিআ");
+    
+    // A dot that doesn't sit between vowels is still a dari
+    assert_eq!(engine.transliterate("ami.tumi"), "আমি।তুমি");
+    // ...as is one between vowels that wouldn't combine, or before a longer word
+    assert_eq!(engine.transliterate("ki.e"), "কি।এ");
+    assert_eq!(engine.transliterate("ami.amar"), "আমি।আমার");
+    assert_eq!(engine.transliterate("ami.amar bari."), "আমি।আমার বারি।");
+}
+
+#[test]
+fn test_empty_and_whitespace_input() {
+    let engine = ObadhEngine::new();
+    
+    assert_eq!(engine.transliterate(""), "");
+    assert!(engine.tokenize("").is_empty());
+    assert!(engine.tokenize_phonetic("").is_empty());
+    
+    // Whitespace-only input is returned unchanged, even whitespace the sanitizer rejects
+    for input in ["   ", "\t", " \n\t "] {
+        assert_eq!(engine.transliterate(input), input);
+    }
+}
+
+#[test]
+fn test_itrans_scheme() {
+    use obadh_engine::Scheme;
+    
+    let engine = ObadhEngine::new().with_scheme(Scheme::Itrans);
+    
+    let examples = [
+        ("a~Nka", "অঙ্ক"),           // ~N for ঙ
+        ("baa.nlaa", "বাংলা"),       // .n for the anusvara, aa for আ
+        ("bhaarata", "ভারত"),        // a is the inherent vowel
+        ("kShamaa", "ক্ষমা"),          // kSh for ক্ষ
+        ("kaa.Nchaa", "কাঁচা"),       // .N for chandrabindu, ch for চ
+        ("duHkha", "দুঃখ"),          // H for visarga
+        ("j~naana", "জ্ঞান"),        // j~n for জ্ঞ
+        ("vidyaa", "বিদ্যা"),        // y for jo-phola
+        ("kaila", "কৈল"),           // ai for ঐ
+    ];
+    
+    for (input, expected) in examples {
+        assert_eq!(engine.transliterate(input), expected, "input: {}", input);
+    }
+    
+    // Avro stays the default
+    assert_eq!(ObadhEngine::new().transliterate("bangla"), "বাংলা");
+    
+    // Setting a scheme adds to the characters the sanitizer already accepts
+    assert!(ObadhEngine::new().sanitize("a~Nka").is_err());
+    let engine = engine.with_scheme(Scheme::Avro);
+    assert!(engine.sanitize("a~Nka").is_ok());
+    assert_eq!(engine.transliterate("bangla"), "বাংলা");
+}
+
+#[test]
+fn test_dari_and_decimal_point() {
+    let engine = ObadhEngine::new();
+    
+    // A '.' inside a number is a decimal point, a sentence-ending '.' is a dari
+    assert_eq!(engine.transliterate("dam 3.5 taka. ses"), "দাম ৩.৫ তাকা। সেস");
+    assert_eq!(engine.transliterate("3."), "৩।");
+    
+    // Dari conversion can be turned off
+    let engine = engine.with_dari_conversion(false);
+    assert_eq!(engine.transliterate("dam 3.5 taka. ses"), "দাম ৩.৫ তাকা. সেস");
+}
+
+#[test]
+fn test_final_consonant_policy() {
+    use obadh_engine::FinalConsonantPolicy;
+    
+    // Standard orthography leaves a final consonant bare
+    let engine = ObadhEngine::new();
+    assert_eq!(engine.transliterate("nam"), "নাম");
+    
+    let engine = ObadhEngine::new().with_final_hasant(FinalConsonantPolicy::Inherent);
+    assert_eq!(engine.transliterate("nam"), "নাম");
+    
+    let engine = ObadhEngine::new().with_final_hasant(FinalConsonantPolicy::Hasant);
+    assert_eq!(engine.transliterate("nam"), "নাম্");
+    assert_eq!(engine.transliterate("kok k"), "কক্ ক্");
+    assert_eq!(engine.transliterate("sotto bondhu"), "সত্ত বন্ধু");
+    assert_eq!(engine.transliterate("sott"), "সত্ত্");
+    
+    // A policy for one call leaves the engine's own in place
+    assert_eq!(engine.transliterate_with_final_policy("kok k", FinalConsonantPolicy::Inherent), "কক ক");
+    assert_eq!(engine.transliterate("kok k"), "কক্ ক্");
+    assert_eq!(ObadhEngine::new().transliterate_with_final_policy("nam", FinalConsonantPolicy::Hasant), "নাম্");
+}
+
+#[test]
+fn test_punctuation_conversion() {
+    // Converted by default: '.' is the dari, ',' and '?' are the same in Bengali
+    let engine = ObadhEngine::new();
+    assert_eq!(engine.transliterate("ami, tumi? se."), "আমি, তুমি? সে।");
+    
+    let engine = ObadhEngine::new().with_punctuation_conversion(true);
+    assert_eq!(engine.transliterate("ami, tumi? se."), "আমি, তুমি? সে।");
+    
+    // Off leaves every punctuation mark untouched
+    let engine = ObadhEngine::new().with_punctuation_conversion(false);
+    assert_eq!(engine.transliterate("ami, tumi? se."), "আমি, তুমি? সে.");
+    assert_eq!(engine.transliterate("$5"), "$৫");
+}
+
+#[test]
+fn test_word_initial_vowel_combinations() {
+    let engine = ObadhEngine::new();
+    
+    let examples = [
+        ("ai", "আই"),
+        ("au", "আউ"),
+        ("eo", "এও"),
+        ("OI", "ঐ"),
+        ("OU", "ঔ"),
+        ("rri", "ঋ"),
+        ("keo", "কেও"),     // 'o' after a vowel is pronounced
+        ("otoeb", "অতএব"),  // ...but stays inherent at the start of a word
+    ];
+    
+    for (input, expected) in examples {
+        assert_eq!(engine.transliterate(input), expected, "input: {}", input);
+    }
+}
+
+#[test]
+fn test_vowel_after_vowel() {
+    let engine = ObadhEngine::new();
+    
+    let examples = [
+        ("eo", "এও"),
+        ("ia", "ইয়া"),
+        ("kia", "কিয়া"),
+        ("ea", "এয়া"),
+        ("ua", "উয়া"),
+        ("ae", "আএ"),      // Only 'a' takes the য় glide
+        ("i'a", "ইআ"),     // ...and not across a syllable break
+        ("i.a", "ইআ"),     // ...or a hiatus marker
+        ("ami-a", "আমি-আ"),
+    ];
+    
+    for (input, expected) in examples {
+        assert_eq!(engine.transliterate(input), expected, "input: {}", input);
+    }
+}
+
+#[test]
+fn test_doubled_vowels() {
+    let engine = ObadhEngine::new();
+    
+    let examples = [
+        ("kii", "কী"),
+        ("bhuu", "ভূ"),
+        ("kee", "কী"),
+        ("boos", "বুস"),
+        ("kaa", "কা"),
+        ("ii", "ঈ"),
+        ("oo", "উ"),
+        // A hiatus marker keeps the letters apart
+        ("ki.i", "কিই"),
+        ("ka.al", "কাআল"),
+        ("de.e", "দেএ"),
+        ("bhu.u", "ভুউ"),
+        ("bo.o", "বঅ"),
+    ];
+    
+    for (input, expected) in examples {
+        assert_eq!(engine.transliterate(input), expected, "input: {}", input);
+    }
+}
+
+#[test]
+fn test_inherent_o_before_vowel() {
+    let engine = ObadhEngine::new();
+    
+    // A consonant with 'o' keeps its inherent vowel, and the vowel after it
+    // is written in its independent form
+    let examples = [
+        ("boi", "বই"),
+        ("doi", "দই"),
+        ("koi", "কই"),
+        ("noy", "নয়"),
+        ("hoy", "হয়"),
+    ];
+    
+    for (input, expected) in examples {
+        assert_eq!(engine.transliterate(input), expected, "input: {}", input);
+    }
+}
+
+#[test]
+fn test_ch_and_chh() {
+    let engine = ObadhEngine::new();
+    
+    // 'c' is চ, while 'ch' and 'chh' are both ছ
+    assert_eq!(engine.transliterate("ci"), "চি");
+    assert_eq!(engine.transliterate("chi"), "ছি");
+    assert_eq!(engine.transliterate("chhi"), "ছি");
+    assert_eq!(engine.transliterate("chobi"), "ছবি");
+    assert_eq!(engine.transliterate("chhobi"), "ছবি");
+    assert_eq!(engine.transliterate("kichhu"), "কিছু");
+    assert_eq!(engine.transliterate("icchha"), "ইচ্ছা");
+    
+    // The same after a reph
+    assert_eq!(engine.transliterate("rrchi"), "র্ছি");
+    assert_eq!(engine.transliterate("rrchhi"), "র্ছি");
+}
+
+#[test]
+fn test_typography() {
+    use obadh_engine::TypographyOptions;
+    
+    let plain = ObadhEngine::new();
+    assert_eq!(plain.transliterate("ami -- tumi"), "আমি -- তুমি");
+    assert_eq!(plain.transliterate("\"ami\""), "\"আমি\"");
+    
+    let engine = ObadhEngine::new().with_typography(TypographyOptions::default().with_em_dash(true).with_curly_quotes(true));
+    assert_eq!(engine.transliterate("ami -- tumi"), "আমি — তুমি");
+    assert_eq!(engine.transliterate("ami--tumi"), "আমি—তুমি");
+    assert_eq!(engine.transliterate("se bollo, \"ami jabo.\""), "সে বল্ল, “আমি জাব।”");
+    assert_eq!(engine.transliterate("(\"ami\")"), "(“আমি”)");
+    // A single hyphen is kept
+    assert_eq!(engine.transliterate("ami - tumi"), "আমি - তুমি");
+    
+    // Independent of dari conversion
+    let no_dari = ObadhEngine::new()
+        .with_dari_conversion(false)
+        .with_typography(TypographyOptions::default().with_em_dash(true));
+    assert_eq!(no_dari.transliterate("ami -- \"tumi\"."), "আমি — \"তুমি\".");
+}
+
+#[test]
+fn test_punctuation_runs() {
+    use obadh_engine::{TokenType, TypographyOptions};
+    
+    // Runs of marks are single tokens, written mark by mark by default
+    let plain = ObadhEngine::new();
+    let tokens = plain.tokenize_only("ki?! na...");
+    let punctuation: Vec<&str> = tokens.iter()
+        .filter(|token| token.token_type == TokenType::Punctuation)
+        .map(|token| token.content.as_str())
+        .collect();
+    assert_eq!(punctuation, ["?!", "..."]);
+    // Every dot of a run is still a dari
+    assert_eq!(plain.transliterate("ki?! na..."), "কি?! না।।।");
+    assert_eq!(plain.transliterate("na."), "না।");
+    assert_eq!(plain.transliterate("na.."), "না।।");
+    assert_eq!(plain.transliterate("na...."), "না।।।।");
+    
+    let engine = ObadhEngine::new().with_typography(TypographyOptions::default().with_ellipsis(true).with_interrobang(true));
+    assert_eq!(engine.transliterate("ki?! na..."), "কি‽ না…");
+    assert_eq!(engine.transliterate("ki!?"), "কি‽");
+}
+
+#[test]
+fn test_interlinear() {
+    let engine = ObadhEngine::new().with_interlinear(true);
+    
+    assert_eq!(engine.transliterate("amar  bari"), "amar (আমার)  bari (বারি)");
+    assert_eq!(engine.transliterate("ami, 12."), "ami (আমি), ১২।");
+}
+
+#[test]
+fn test_letter_and_punctuation_scripts() {
+    use obadh_engine::Script;
+    
+    let text = "ami 12 Ta gan gai.";
+    let engine = |letters: Script, punctuation: Script| {
+        ObadhEngine::new().with_letter_script(letters).with_punctuation_script(punctuation)
+    };
+    
+    // The default writes both in Bengali
+    assert_eq!(ObadhEngine::new().transliterate(text), "আমি ১২ টা গান গাই।");
+    assert_eq!(engine(Script::Bengali, Script::Bengali).transliterate(text), "আমি ১২ টা গান গাই।");
+    assert_eq!(engine(Script::Bengali, Script::Latin).transliterate(text), "আমি ১২ টা গান গাই.");
+    assert_eq!(engine(Script::Latin, Script::Bengali).transliterate(text), "ami 12 Ta gan gai।");
+    assert_eq!(engine(Script::Latin, Script::Latin).transliterate(text), text);
+}
+
+#[test]
+fn test_quick_transliteration() {
+    let expected = ObadhEngine::new().transliterate("ami banglay gan gai.");
+    
+    assert_eq!(obadh_engine::transliterate("ami banglay gan gai."), expected);
+    assert_eq!(ObadhEngine::quick("ami banglay gan gai."), expected);
+    
+    // Later calls reuse the shared engine
+    assert_eq!(ObadhEngine::quick("tumi"), "তুমি");
+}
+
+#[test]
+fn test_apostrophe_policy() {
+    use obadh_engine::ApostrophePolicy;
+    
+    // By default an apostrophe inside a word starts a new syllable
+    let engine = ObadhEngine::new();
+    assert_eq!(engine.transliterate("amar'i"), "আমারই");
+    assert_eq!(engine.transliterate("tOmar'o"), "তোমারও");
+    assert_eq!(engine.transliterate("k'to"), "কত");
+    
+    // Apostrophes around a word are still quotation marks
+    assert_eq!(engine.transliterate("'ami'"), "'আমি'");
+    
+    let engine = ObadhEngine::new().with_apostrophe_policy(ApostrophePolicy::Drop);
+    assert_eq!(engine.transliterate("amar'i"), "আমারি");
+    assert_eq!(engine.transliterate("k'to"), "ক্ত");
+    
+    let engine = ObadhEngine::new().with_apostrophe_policy(ApostrophePolicy::Punctuation);
+    assert_eq!(engine.transliterate("amar'i"), "আমার'ই");
+}
+
+#[test]
+fn test_whitespace_collapse() {
+    let engine = ObadhEngine::new().with_whitespace_collapse(true);
+    assert_eq!(engine.transliterate("ami   tumi"), "আমি তুমি");
+    assert_eq!(engine.transliterate("ami\t\t tumi"), "আমি তুমি");
+    assert_eq!(engine.transliterate("ami\n\ntumi "), "আমি তুমি ");
+    
+    // Newlines can be kept, one per run
+    let engine = engine.with_newlines_kept(true);
+    assert_eq!(engine.transliterate("ami  \n\n tumi\t"), "আমি\nতুমি ");
+    
+    // Off by default
+    assert_eq!(ObadhEngine::new().transliterate("ami   tumi"), "আমি   তুমি");
+}
+
+#[test]
+fn test_diphthong_case_sensitivity() {
+    // By default only "OI" and "OU" are diphthongs
+    let engine = ObadhEngine::new();
+    assert_eq!(engine.transliterate("boi"), "বই");
+    assert_eq!(engine.transliterate("bOI"), "বৈ");
+    assert_eq!(engine.transliterate("bou"), "বউ");
+    assert_eq!(engine.transliterate("bOU"), "বৌ");
+    
+    let engine = ObadhEngine::new().with_diphthong_case_sensitivity(false);
+    assert_eq!(engine.transliterate("boi"), "বৈ");
+    assert_eq!(engine.transliterate("bou"), "বৌ");
+    assert_eq!(engine.transliterate("Oi"), "ঐ");
+    // A hiatus marker still separates the vowels
+    assert_eq!(engine.transliterate("bo.i"), "বই");
+}
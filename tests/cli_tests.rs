@@ -0,0 +1,142 @@
+use std::process::Command;
+
+#[test]
+fn test_benchmark_total_is_sum_of_components() {
+    let output = Command::new(env!("CARGO_BIN_EXE_obadh"))
+        .args(["--benchmark", "200", "--debug", "ami banglay gan gai"])
+        .output()
+        .expect("failed to run obadh");
+    assert!(output.status.success());
+    
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let benchmark = &json["benchmark"];
+    let ms = |field: &str| benchmark[field].as_f64().unwrap();
+    
+    let components = ms("avg_sanitize_ms") + ms("avg_tokenize_ms") + ms("avg_transliterate_ms");
+    assert!((ms("avg_total_ms") - components).abs() < 1e-5,
+            "average total {} vs component sum {}", ms("avg_total_ms"), components);
+    
+    // The total run time is the average total over every iteration
+    assert!((ms("total_run_time_ms") - ms("avg_total_ms") * 200.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_multiple_input_files() {
+    let dir = std::env::temp_dir().join(format!("obadh_cli_{}", std::process::id()));
+    let out_dir = dir.join("out");
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let first = dir.join("first.txt");
+    let second = dir.join("second.txt");
+    std::fs::write(&first, "ami\ntumi\n").unwrap();
+    std::fs::write(&second, "gan gai\n").unwrap();
+    
+    // Files are processed in order, with their outputs concatenated
+    let output = Command::new(env!("CARGO_BIN_EXE_obadh"))
+        .arg("--input-file").arg(&first)
+        .arg("--input-file").arg(&second)
+        .output()
+        .expect("failed to run obadh");
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "আমি\nতুমি\nগান গাই\n");
+    
+    // With an output directory, each file gets its own output
+    let status = Command::new(env!("CARGO_BIN_EXE_obadh"))
+        .arg("-i").arg(&first)
+        .arg("-i").arg(&second)
+        .arg("--output-file").arg(&out_dir)
+        .status()
+        .expect("failed to run obadh");
+    assert!(status.success());
+    assert_eq!(std::fs::read_to_string(out_dir.join("first.txt")).unwrap(), "আমি\nতুমি\n");
+    assert_eq!(std::fs::read_to_string(out_dir.join("second.txt")).unwrap(), "গান গাই\n");
+    
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_explain() {
+    let output = Command::new(env!("CARGO_BIN_EXE_obadh"))
+        .args(["explain", "ami"])
+        .output()
+        .expect("failed to run obadh");
+    assert!(output.status.success());
+    
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("input     ami\n"));
+    assert!(stdout.contains("output    আমি\n"));
+    assert!(stdout.contains("phonemes  ami\n"));
+    assert!(stdout.contains("tokens\n  Word \"ami\" at 0\n"));
+    assert!(stdout.contains("    syllable \"mi\" মি (open)\n      ConsonantWithVowel \"mi\"\n"));
+    
+    // Letters read by the ASCII fallback keep their syllables
+    let output = Command::new(env!("CARGO_BIN_EXE_obadh"))
+        .args(["explain", "xaxaxaxa"])
+        .output()
+        .expect("failed to run obadh");
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap().matches("syllable \"xa\"").count(), 4);
+    
+    // Without the subcommand the text is transliterated as before
+    let output = Command::new(env!("CARGO_BIN_EXE_obadh"))
+        .arg("ami")
+        .output()
+        .expect("failed to run obadh");
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "আমি\n");
+}
+
+#[test]
+fn test_output_file_safety() {
+    let dir = std::env::temp_dir().join(format!("obadh_cli_out_{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("a")).unwrap();
+    std::fs::create_dir_all(dir.join("b")).unwrap();
+    let notes = dir.join("notes.txt");
+    std::fs::write(&notes, "ami\n").unwrap();
+    std::fs::write(dir.join("a/x.txt"), "ami\n").unwrap();
+    std::fs::write(dir.join("b/x.txt"), "tumi\n").unwrap();
+    let obadh = |args: &[&std::ffi::OsStr]| Command::new(env!("CARGO_BIN_EXE_obadh")).args(args).output().expect("failed to run obadh");
+    
+    // An output that resolves to an input is refused, leaving the input as it was
+    for output in [dir.as_os_str(), notes.as_os_str()] {
+        let result = obadh(&["-i".as_ref(), notes.as_os_str(), "-o".as_ref(), output]);
+        assert!(!result.status.success());
+        assert!(String::from_utf8(result.stderr).unwrap().contains("refusing to overwrite input file"));
+        assert_eq!(std::fs::read_to_string(&notes).unwrap(), "ami\n");
+    }
+    
+    // Two inputs with the same name can't share an output directory
+    let out = dir.join("out");
+    std::fs::create_dir(&out).unwrap();
+    let result = obadh(&["-i".as_ref(), dir.join("a/x.txt").as_os_str(), "-i".as_ref(), dir.join("b/x.txt").as_os_str(), "-o".as_ref(), out.as_os_str()]);
+    assert!(!result.status.success());
+    assert!(String::from_utf8(result.stderr).unwrap().contains("two input files would be written to"));
+    assert_eq!(std::fs::read_dir(&out).unwrap().count(), 0);
+    
+    // A trailing slash names a directory, created if missing
+    let missing = format!("{}/", dir.join("missing").display());
+    assert!(obadh(&["-i".as_ref(), notes.as_os_str(), "-o".as_ref(), missing.as_ref()]).status.success());
+    assert_eq!(std::fs::read_to_string(dir.join("missing/notes.txt")).unwrap(), "আমি\n");
+    
+    // Without one, a missing path is a file
+    let file = dir.join("single");
+    assert!(obadh(&["-i".as_ref(), notes.as_os_str(), "-o".as_ref(), file.as_os_str()]).status.success());
+    assert_eq!(std::fs::read_to_string(&file).unwrap(), "আমি\n");
+    
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_crlf_output_independent_of_jobs() {
+    let run = |jobs: &str| {
+        let output = Command::new(env!("CARGO_BIN_EXE_obadh"))
+            .args(["--jobs", jobs, "ami\r\ntumi\r\ngan gai\r\n"])
+            .output()
+            .expect("failed to run obadh");
+        assert!(output.status.success());
+        String::from_utf8(output.stdout).unwrap()
+    };
+    
+    // CRLF line endings are kept whatever the number of threads
+    let single = run("1");
+    assert_eq!(single, "আমি\r\nতুমি\r\nগান গাই\r\n\n");
+    assert_eq!(run("4"), single);
+}
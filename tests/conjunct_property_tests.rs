@@ -0,0 +1,65 @@
+use std::collections::BTreeMap;
+
+use obadh_engine::definitions::{consonants, special_rules};
+use obadh_engine::ObadhEngine;
+use proptest::prelude::*;
+use proptest::sample::select;
+
+/// Bengali consonants: U+0995–U+09B9 plus ড়, ঢ় and য় (written with a nukta)
+fn bengali_consonants() -> Vec<String> {
+    ('\u{0995}'..='\u{09B9}').map(String::from)
+        .chain(["\u{09A1}\u{09BC}", "\u{09A2}\u{09BC}", "\u{09AF}\u{09BC}"].map(String::from))
+        .collect()
+}
+
+/// Map each Bengali consonant with a Roman key to its (alphabetically first) key
+fn roman_keys() -> BTreeMap<String, &'static str> {
+    let mut keys = BTreeMap::new();
+    
+    for (&roman, &bengali) in consonants().entries() {
+        let key = keys.entry(bengali.to_string()).or_insert(roman);
+        if roman < *key {
+            *key = roman;
+        }
+    }
+    
+    keys
+}
+
+/// A Bengali consonant from the consonant range that has a Roman key
+fn mapped_consonant() -> impl Strategy<Value = (String, &'static str)> {
+    let keys = roman_keys();
+    
+    select(bengali_consonants()).prop_filter_map("consonant without a Roman key", move |consonant| {
+        keys.get(&consonant).map(|roman| (consonant, *roman))
+    })
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+    
+    #[test]
+    fn explicit_conjunct_joins_any_two_consonants(
+        (first, first_roman) in mapped_consonant(),
+        (second, second_roman) in mapped_consonant(),
+    ) {
+        let engine = ObadhEngine::new();
+        let input = format!("{},,{}", first_roman, second_roman);
+        
+        prop_assert_eq!(engine.transliterate(&input), format!("{}\u{09CD}{}", first, second));
+    }
+}
+
+#[test]
+fn test_special_conjuncts_round_trip() {
+    let engine = ObadhEngine::new();
+    
+    // Every special form that is a consonant cluster comes out as its mapped form
+    for (&roman, &bengali) in special_rules().entries() {
+        if bengali.contains('\u{09CD}') && !bengali.starts_with('অ') {
+            assert_eq!(engine.transliterate(roman), bengali, "input: {}", roman);
+        }
+    }
+    
+    assert_eq!(engine.transliterate("kkh"), "ক্ষ");
+}
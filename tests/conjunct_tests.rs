@@ -1,4 +1,4 @@
-use obadh_engine::{Tokenizer, PhoneticUnitType};
+use obadh_engine::{Tokenizer, PhoneticUnitType, ObadhEngine};
 
 #[test]
 fn test_basic_conjunct_formation() {
@@ -369,4 +369,244 @@ fn test_comparison_normal_and_reph() {
     assert_eq!(reph_units[0].text, "ko");
     assert_eq!(reph_units[1].unit_type, PhoneticUnitType::RephOverConsonantWithTerminator);
     assert_eq!(reph_units[1].text, "rrmo");
-} 
\ No newline at end of file
+} 
+#[test]
+fn test_o_compound_stopper_tokenization() {
+    let tokenizer = Tokenizer::new();
+    
+    // 'o' closes the consonant before it, so no conjunct forms across it
+    let units = tokenizer.tokenize_word("kokkok");
+    let texts: Vec<&str> = units.iter().map(|unit| unit.text.as_str()).collect();
+    assert_eq!(texts, ["ko", "k,,ko", "k"]);
+    assert_eq!(units[0].unit_type, PhoneticUnitType::ConsonantWithTerminator);
+    assert_eq!(units[1].unit_type, PhoneticUnitType::ConjunctWithTerminator);
+    assert_eq!(units[2].unit_type, PhoneticUnitType::Consonant);
+    
+    // A reph cluster is closed by 'o' the same way
+    let units = tokenizer.tokenize_word("porrjonto");
+    let texts: Vec<&str> = units.iter().map(|unit| unit.text.as_str()).collect();
+    assert_eq!(texts, ["po", "rrjo", "n,,to"]);
+    assert_eq!(units[1].unit_type, PhoneticUnitType::RephOverConsonantWithTerminator);
+}
+
+#[test]
+fn test_o_compound_stopper_in_long_words() {
+    let engine = ObadhEngine::new();
+    
+    let examples = [
+        ("kok", "কক"),               // stopper between two consonants
+        ("kk", "ক্ক"),                // conjunct without a stopper
+        ("kokkok", "কক্কক"),         // stopper, conjunct, stopper
+        ("kOnnOkO", "কোন্নোকো"),      // explicit 'O' vowels around a conjunct
+        ("bondhonokkho", "বন্ধনক্ষ"),  // special conjunct closed by 'o'
+        ("pOrrjonto", "পোর্জন্ত"),     // reph closed by 'o', then a conjunct
+        ("sonkoto", "সন্কত"),        // conjunct, then two closed syllables
+        ("kolkata", "কল্কাতা"),       // conjunct across a syllable boundary
+        ("songsottO", "সংসত্তো"),     // anusvara, stopper and conjunct
+    ];
+    
+    for (input, expected) in examples {
+        assert_eq!(engine.transliterate(input), expected, "input: {}", input);
+    }
+}
+
+#[test]
+fn test_disabling_reph() {
+    let engine = ObadhEngine::new().with_reph(false);
+    
+    // Without reph, 'rr' is র with a visible hasant, and before য the ya-phala form
+    assert_eq!(engine.transliterate("korrmo"), "কর্\u{200C}ম");
+    assert_eq!(engine.transliterate("sorrtok"), "সর্\u{200C}তক");
+    assert_eq!(engine.transliterate("rrzab"), "র\u{200D}্যাব");
+    
+    assert_eq!(ObadhEngine::new().transliterate("korrmo"), "কর্ম");
+}
+
+#[test]
+fn test_reph_zwj() {
+    let engine = ObadhEngine::new().with_reph_zwj(true);
+    
+    // Over য, য় and র the reph is joined with a ZWJ
+    assert_eq!(engine.transliterate("sUrrzo"), "সূর্\u{200D}য");
+    assert_eq!(engine.transliterate("rry"), "র্\u{200D}য়");
+    assert_eq!(engine.transliterate("rrra"), "র্\u{200D}রা");
+    
+    // Other consonants, and the default engine, keep the plain reph
+    assert_eq!(engine.transliterate("korrmo"), "কর্ম");
+    assert_eq!(ObadhEngine::new().transliterate("sUrrzo"), "সূর্য");
+    
+    // Without a reph there is nothing to join
+    assert_eq!(engine.with_reph(false).transliterate("rrzab"), "র\u{200D}্যাব");
+}
+
+#[test]
+fn test_escaped_double_r() {
+    let engine = ObadhEngine::new();
+    
+    // A backslash between the r's writes two ras instead of a reph
+    assert_eq!(engine.transliterate("r\\r"), "রর");
+    assert_eq!(engine.transliterate("r\\ra"), "ররা");
+    assert_eq!(engine.transliterate("bor\\ro"), "বরর");
+    
+    // Plain "rr" is still the reph
+    assert_eq!(engine.transliterate("rr"), "র্");
+    assert_eq!(engine.transliterate("korrmo"), "কর্ম");
+    
+    // A backslash elsewhere is punctuation
+    assert_eq!(engine.transliterate("a\\b"), "আ\\ব");
+}
+
+#[test]
+fn test_standalone_reph_rules() {
+    let tokenizer = Tokenizer::new();
+    let engine = ObadhEngine::new();
+    
+    // 'rr' + consonant is a reph over that consonant
+    let units = tokenizer.tokenize_word("rrka");
+    assert_eq!(units[0].unit_type, PhoneticUnitType::RephOverConsonantWithVowel);
+    assert_eq!(engine.transliterate("rrka"), "র্কা");
+    
+    // 'rr' + 'i' is the vocalic R vowel
+    let units = tokenizer.tokenize_word("rri");
+    assert_eq!(units.len(), 1);
+    assert_eq!(units[0].unit_type, PhoneticUnitType::Vowel);
+    assert_eq!(engine.transliterate("rri"), "ঋ");
+    assert_eq!(engine.transliterate("krri"), "কৃ");
+    
+    // 'rr' + any other vowel is র with the vowel sign
+    let units = tokenizer.tokenize_word("rrekha");
+    assert_eq!(units[0].unit_type, PhoneticUnitType::ConsonantWithVowel);
+    assert_eq!(units[0].text, "re");
+    assert_eq!(units[0].position, 0);
+    
+    let examples = [
+        ("rrekha", "রেখা"),
+        ("rre", "রে"),
+        ("rrA", "রা"),
+        ("rrOI", "রৈ"),
+        ("rro", "র"),
+    ];
+    for (input, expected) in examples {
+        assert_eq!(engine.transliterate(input), expected, "input: {}", input);
+    }
+    
+    // A bare 'rr' is still a reph with hasant
+    assert_eq!(engine.transliterate("rr"), "র্");
+}
+
+#[test]
+fn test_reph_window_merges_consonant_and_vowel() {
+    let tokenizer = Tokenizer::new();
+    let engine = ObadhEngine::new();
+    
+    // "rr", "m" and "i" are three units before merging; the reph takes both in one step
+    for (input, unit_type, expected) in [
+        ("rrmi", PhoneticUnitType::RephOverConsonantWithVowel, "র্মি"),
+        ("rrmO", PhoneticUnitType::RephOverConsonantWithVowel, "র্মো"),
+        ("rrmo", PhoneticUnitType::RephOverConsonantWithTerminator, "র্ম"),
+        ("rrm", PhoneticUnitType::RephOverConsonant, "র্ম"),
+    ] {
+        let units = tokenizer.tokenize_word(input);
+        assert_eq!(units.len(), 1, "input: {}", input);
+        assert_eq!(units[0].unit_type, unit_type, "input: {}", input);
+        assert_eq!(units[0].text, input);
+        assert_eq!(engine.transliterate(input), expected);
+    }
+    
+    // The merged unit keeps the position of the "rr" and the units after it are untouched
+    let units = tokenizer.tokenize_word("karrtik");
+    assert_eq!(units.len(), 3);
+    assert_eq!(units[1].text, "rrti");
+    assert_eq!(units[1].position, 2);
+    assert_eq!(units[2].text, "k");
+    assert_eq!(units[2].unit_type, PhoneticUnitType::Consonant);
+    assert_eq!(engine.transliterate("karrtik"), "কার্তিক");
+}
+
+#[test]
+fn test_virama_style() {
+    use obadh_engine::ViramaStyle;
+    
+    assert_eq!(ObadhEngine::new().transliterate("kk"), "ক্ক");
+    
+    let engine = ObadhEngine::new().with_virama_style(ViramaStyle::WithZwj);
+    assert_eq!(engine.transliterate("kk"), "ক্\u{200D}ক");
+    assert_eq!(engine.transliterate("k,,t"), "ক্\u{200D}ত");
+    assert_eq!(engine.transliterate("biSw"), "বিশ্\u{200D}ব");
+    // The reph keeps the plain hasant
+    assert_eq!(engine.transliterate("korrmo"), "কর্ম");
+    
+    let engine = ObadhEngine::new().with_virama_style(ViramaStyle::Visible);
+    assert_eq!(engine.transliterate("kk"), "ক্\u{200C}ক");
+}
+
+#[test]
+fn test_conjunct_profile() {
+    use obadh_engine::{ConjunctProfile, ConjunctType};
+    
+    let engine = ObadhEngine::new();
+    
+    // জ্ঞ and ক্ষ are flagged as special forms
+    let biggan = engine.conjunct_profile("biggan");
+    assert_eq!(biggan, ConjunctProfile { regular: 0, special: 1, reph: 0 });
+    assert!(biggan.has_special());
+    
+    let kkhmo = engine.conjunct_profile("kkhmo");
+    assert_eq!(kkhmo.count(ConjunctType::Special), 1);
+    assert!(kkhmo.has_special());
+    
+    // The anusvara in "songskriti" is not a conjunct, স্ক্র is a regular one
+    let songskriti = engine.conjunct_profile("songskriti");
+    assert_eq!(songskriti, ConjunctProfile { regular: 1, special: 0, reph: 0 });
+    assert!(!songskriti.has_special());
+    
+    // A reph is counted on its own
+    let surzo = engine.conjunct_profile("sUrrzo");
+    assert_eq!(surzo.count(ConjunctType::Reph), 1);
+    assert_eq!(surzo.total(), 1);
+    
+    assert_eq!(engine.conjunct_profile("ami").total(), 0);
+    assert_eq!(engine.conjunct_profile("brohma"), ConjunctProfile { regular: 1, special: 1, reph: 0 });
+}
+
+#[test]
+fn test_registered_special_form() {
+    use obadh_engine::engine::Transliterator;
+    
+    // By default "ksh" is ক and শ joined with a hasant
+    assert_eq!(ObadhEngine::new().transliterate("kshoma"), "ক্শমা");
+    
+    let engine = ObadhEngine::new().with_special_form("ksh", "ক্ষ");
+    assert_eq!(engine.transliterate("kshoma"), "ক্ষমা");
+    assert_eq!(engine.transliterate("kshiti"), "ক্ষিতি");
+    assert_eq!(engine.conjunct_profile("kshoma").count(obadh_engine::ConjunctType::Special), 1);
+    
+    // The built-in special forms are still there underneath
+    assert_eq!(engine.transliterate("biggan"), "বিজ্ঞান");
+    
+    // A registered form overrides a built-in one
+    let mut transliterator = Transliterator::new();
+    transliterator.register_special("gg", "গ্গ");
+    assert_eq!(transliterator.transliterate("gga"), "গ্গা");
+    assert!(transliterator.supported_mappings().contains(&("gg".to_string(), "গ্গ".to_string())));
+}
+
+#[test]
+fn test_registered_special_form_rejects_non_letters() {
+    use obadh_engine::engine::Transliterator;
+    
+    // An empty or non-letter sequence is ignored instead of matching everywhere
+    for sequence in ["", ",,", "k-h", " "] {
+        assert_eq!(ObadhEngine::new().with_special_form(sequence, "X").transliterate("ami"), "আমি", "sequence: {:?}", sequence);
+        let transliterator = Transliterator::new().with_special_form(sequence, "X");
+        assert!(!transliterator.supported_mappings().iter().any(|(_, bengali)| bengali == "X"), "sequence: {:?}", sequence);
+    }
+    
+    // Registered forms are looked up in a fixed order
+    let mut transliterator = Transliterator::new();
+    transliterator.register_special("zgh", "ক্ষ");
+    transliterator.register_special("jgh", "ক্ষ");
+    for _ in 0..8 {
+        assert_eq!(transliterator.cluster_to_roman("ক্ষ").as_deref(), Some("jgh"));
+    }
+}
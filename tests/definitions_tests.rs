@@ -0,0 +1,56 @@
+use obadh_engine::definitions::{consonant_system, consonants, vowels};
+use obadh_engine::ObadhEngine;
+
+/// Every Roman key of a table whose transliteration differs from the expected
+/// Bengali, as `key: expected != actual`
+fn unreachable<'a>(cases: impl Iterator<Item = (&'a str, String, String)>) -> Vec<String> {
+    let engine = ObadhEngine::new();
+    let mut failures: Vec<String> = cases
+        .filter_map(|(key, input, expected)| {
+            let actual = engine.transliterate(&input);
+            (actual != expected).then(|| format!("{}: {} != {}", key, expected, actual))
+        })
+        .collect();
+    failures.sort();
+    failures
+}
+
+#[test]
+fn test_every_consonant_key_is_reachable() {
+    // An explicit 'a' keeps the consonant from forming a special form with
+    // whatever follows it
+    let failures = unreachable(consonants().entries().map(|(&roman, &bengali)| {
+        (roman, format!("{}a", roman), format!("{}া", bengali))
+    }));
+    
+    assert!(failures.is_empty(), "unreachable consonant keys:\n{}", failures.join("\n"));
+}
+
+#[test]
+fn test_consonant_system_matches_consonants() {
+    let system = consonant_system();
+    let mut grouped: Vec<(&str, &str)> = [
+        system.velars, system.palatals, system.retroflexes, system.dentals,
+        system.labials, system.semivowels, system.fricatives, system.special,
+    ].concat();
+    let mut flat: Vec<(&str, &str)> = consonants().entries().map(|(&roman, &bengali)| (roman, bengali)).collect();
+    grouped.sort();
+    flat.sort();
+    
+    // Every key is in exactly one group, with the same Bengali as the flat map
+    assert_eq!(grouped, flat);
+}
+
+#[test]
+fn test_every_vowel_key_is_reachable() {
+    // Standalone, and after a consonant, where the vowel sign is written
+    let failures = unreachable(vowels().entries().flat_map(|(&roman, vowel)| {
+        let sign = vowel.dependent.unwrap_or_default();
+        [
+            (roman, roman.to_string(), vowel.independent.to_string()),
+            (roman, format!("k{}", roman), format!("ক{}", sign)),
+        ]
+    }));
+    
+    assert!(failures.is_empty(), "unreachable vowel keys:\n{}", failures.join("\n"));
+}
@@ -0,0 +1,38 @@
+use obadh_engine::{Dictionary, ObadhEngine};
+
+#[test]
+fn test_dictionary_parsing() {
+    let dictionary = Dictionary::from_tsv("# comment\n\nbhalo\tভালো\ninvalid line\n  jonno\tজন্য  \n");
+    
+    assert_eq!(dictionary.len(), 2);
+    assert_eq!(dictionary.get("bhalo"), Some("ভালো"));
+    assert_eq!(dictionary.get("jonno"), Some("জন্য"));
+    assert_eq!(dictionary.get("invalid"), None);
+}
+
+#[test]
+fn test_dictionary_overrides_rules() {
+    let mut dictionary = Dictionary::new();
+    dictionary.insert("bhalo", "ভালো");
+    
+    // Without a dictionary the rules leave out the final o-kar
+    let engine = ObadhEngine::new();
+    assert_eq!(engine.transliterate("bhalo"), "ভাল");
+    
+    // Only whole-word matches are replaced
+    let engine = ObadhEngine::new().with_dictionary(dictionary);
+    assert_eq!(engine.transliterate("khub bhalo."), "খুব ভালো।");
+    assert_eq!(engine.transliterate("bhalobasa"), "ভালবাসা");
+}
+
+#[cfg(feature = "builtin-dict")]
+#[test]
+fn test_builtin_dictionary() {
+    let dictionary = Dictionary::builtin();
+    assert!(!dictionary.is_empty());
+    assert_eq!(dictionary.get("biggan"), Some("বিজ্ঞান"));
+    
+    // An ambiguous word resolves to its dictionary form
+    let engine = ObadhEngine::new().with_dictionary(Dictionary::builtin());
+    assert_eq!(engine.transliterate("tomar kosto"), "তোমার কষ্ট");
+}
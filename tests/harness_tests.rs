@@ -0,0 +1,38 @@
+use std::path::Path;
+
+use obadh_engine::engine::{run_cases, Transliterator, TestReport};
+
+#[test]
+fn test_run_cases_fixture() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/reference_cases.tsv");
+    let report = run_cases(&path).expect("fixture should be readable");
+    
+    assert_eq!(report.total, 5);
+    assert_eq!(report.passed, 4);
+    assert_eq!(report.failed, 1);
+    assert!(!report.all_passed());
+    
+    // Line numbers count comments and blank lines in the file
+    let mismatch = report.first_mismatch().unwrap();
+    assert_eq!(mismatch.line, 9);
+    assert_eq!(mismatch.roman, "bhalo");
+    assert_eq!(mismatch.expected, "ভালো");
+    assert_eq!(mismatch.actual, "ভাল");
+    
+    // The report serializes for external tooling
+    let json = serde_json::to_string(&report).unwrap();
+    assert!(json.contains("\"passed\":4"));
+    assert!(json.contains("\"roman\":\"bhalo\""));
+}
+
+#[test]
+fn test_report_from_tsv() {
+    let transliterator = Transliterator::new();
+    
+    let report = TestReport::from_tsv(&transliterator, "ami\tআমি\nno tab here\n");
+    assert_eq!(report.total, 1);
+    assert!(report.all_passed());
+    assert!(report.first_mismatch().is_none());
+    
+    assert!(run_cases(Path::new("tests/fixtures/missing.tsv")).is_err());
+}
@@ -1,4 +1,4 @@
-use obadh_engine::{Tokenizer, PhoneticUnitType};
+use obadh_engine::{ObadhEngine, Tokenizer, PhoneticUnitType};
 
 #[test]
 fn test_explicit_hasant_notation() {
@@ -95,4 +95,33 @@ fn test_mixed_hasant_notation() {
     
     // Verify the output structure
     assert!(!units.is_empty());
-} 
\ No newline at end of file
+}
+
+#[test]
+fn test_word_final_hasant() {
+    let tokenizer = Tokenizer::new();
+    
+    // The trailing hasant joins the final consonant as one unit
+    let units = tokenizer.tokenize_word("bak,,");
+    assert_eq!(units.len(), 2);
+    assert_eq!(units[1].text, "k,,");
+    assert_eq!(units[1].unit_type, PhoneticUnitType::ConsonantWithHasant);
+    assert_eq!(units[1].position, 2);
+    assert_eq!(units[1].roman_display(), "k,,");
+    
+    // and the final conjunct
+    let units = tokenizer.tokenize_word("bakk,,");
+    assert_eq!(units[1].text, "k,,k,,");
+    assert_eq!(units[1].unit_type, PhoneticUnitType::Conjunct);
+    assert_eq!(units[1].roman_display(), "kk,,");
+    
+    let engine = ObadhEngine::new();
+    assert_eq!(engine.transliterate("bak,,"), "বাক্");
+    assert_eq!(engine.transliterate("k,,"), "ক্");
+    assert_eq!(engine.transliterate("bakk,,"), "বাক্ক্");
+    assert_eq!(engine.transliterate("n,,d,,r,,"), "ন্দ্র্");
+    assert_eq!(engine.transliterate("bak,, ami"), "বাক্ আমি");
+    
+    // Without it the final consonant keeps its inherent vowel
+    assert_eq!(engine.transliterate("bak"), "বাক");
+}
@@ -0,0 +1,118 @@
+use obadh_engine::{ObadhEngine, PhoneticUnit, PhoneticUnitType, Token, TokenType};
+use obadh_engine::engine::{Annotation, Transliterator};
+use obadh_engine::wasm::{ObadhaWasm, TransliterationOptions, TransliterationResult};
+
+#[test]
+fn test_json_output_is_byte_stable() {
+    let engine = ObadhEngine::new();
+    
+    let result = TransliterationResult::new(&engine, "ami", false);
+    assert_eq!(
+        serde_json::to_string(&result).unwrap(),
+        r#"{"input":"ami","output":"আমি"}"#
+    );
+    
+    let result = TransliterationResult::new(&engine, "ami, cha^d", true);
+    let expected = concat!(
+        r#"{"input":"ami, cha^d","output":"আমি, ছাঁদ","token_analysis":["#,
+        r#"{"content":"ami","position":0,"type":"Word","transliterated":"আমি","phonetic_units":["#,
+        r#"{"text":"a","position":0,"type":"Vowel","has_chandrabindu":false,"has_visarga":false},"#,
+        r#"{"text":"mi","position":1,"type":"ConsonantWithVowel","has_chandrabindu":false,"has_visarga":false}]},"#,
+        r#"{"content":",","position":3,"type":"Punctuation"},"#,
+        r#"{"content":" ","position":4,"type":"Whitespace"},"#,
+        r#"{"content":"cha^d","position":5,"type":"Word","transliterated":"ছাঁদ","phonetic_units":["#,
+        r#"{"text":"cha^","position":0,"type":"ChandrabinduWithConsonantAndVowel","has_chandrabindu":true,"has_visarga":false},"#,
+        r#"{"text":"d","position":4,"type":"Consonant","has_chandrabindu":false,"has_visarga":false}]}]}"#,
+    );
+    assert_eq!(serde_json::to_string(&result).unwrap(), expected);
+}
+
+#[test]
+fn test_wasm_analysis_is_byte_stable() {
+    let wasm = ObadhaWasm::new();
+    
+    // Words are broken down into units without being transliterated
+    let expected = concat!(
+        r#"[{"content":"ami","position":0,"type":"Word","phonetic_units":["#,
+        r#"{"text":"a","position":0,"type":"Vowel","has_chandrabindu":false,"has_visarga":false},"#,
+        r#"{"text":"mi","position":1,"type":"ConsonantWithVowel","has_chandrabindu":false,"has_visarga":false}]},"#,
+        r#"{"content":",","position":3,"type":"Punctuation"},"#,
+        r#"{"content":" ","position":4,"type":"Whitespace"},"#,
+        r#"{"content":"12","position":5,"type":"Number"}]"#,
+    );
+    assert_eq!(serde_json::to_string(&wasm.analysis("ami, 12")).unwrap(), expected);
+}
+
+#[test]
+fn test_analysis_round_trip() {
+    let engine = ObadhEngine::new();
+    let transliterator = Transliterator::new();
+    let input = "ami du:kho pai";
+    
+    let tokens = engine.tokenize_only(input);
+    let units = engine.tokenize_phonetic("du:kho");
+    let annotations = transliterator.annotate(input);
+    
+    let json = serde_json::to_string(&(&tokens, &units, &annotations)).unwrap();
+    let (tokens_back, units_back, annotations_back): (Vec<Token>, Vec<PhoneticUnit>, Vec<Annotation>) =
+        serde_json::from_str(&json).unwrap();
+    
+    assert_eq!(tokens_back, tokens);
+    assert_eq!(units_back, units);
+    assert_eq!(annotations_back, annotations);
+    
+    // Key fields survive in readable form
+    assert_eq!(tokens_back[2].content, "du:kho");
+    assert_eq!(tokens_back[2].token_type, TokenType::Word);
+    assert_eq!(units_back[1].unit_type, PhoneticUnitType::SpecialForm);
+    let output: String = annotations_back.iter().map(|a| a.bengali.as_str()).collect();
+    assert_eq!(output, engine.transliterate(input));
+    assert!(json.contains(r#""unit_type":"ConsonantWithVowel""#));
+}
+
+#[test]
+fn test_inherent_final_option() {
+    let mut wasm = ObadhaWasm::new();
+    let mut options = TransliterationOptions::new();
+    
+    // The default engine already writes the inherent vowel
+    assert_eq!(wasm.transliterate_result("rahim", &options).output, "রাহিম");
+    
+    // With a final hasant on the engine, the option restores it for a single call
+    wasm.set_final_hasant(true);
+    assert_eq!(wasm.transliterate_result("rahim", &options).output, "রাহিম্");
+    options.inherent_final = true;
+    assert_eq!(wasm.transliterate_result("rahim", &options).output, "রাহিম");
+    
+    // The engine's policy is back in place for the next call
+    assert_eq!(wasm.transliterate("rahim"), "রাহিম্");
+    wasm.set_final_hasant(false);
+    assert_eq!(wasm.transliterate("rahim"), "রাহিম");
+    
+    // Options from JS without the field keep the engine's policy
+    let options: TransliterationOptions = serde_json::from_str(r#"{"debug":false,"verbose":false}"#).unwrap();
+    assert!(!options.inherent_final);
+}
+
+#[test]
+fn test_batch_json_stream() {
+    let engine = ObadhEngine::new();
+    
+    let texts = ["ami", "", "tumi \"bhalo\"", "amar sonar bangla"];
+    let mut buffer = Vec::new();
+    engine.batch_transliterate_json_stream(texts, &mut buffer).unwrap();
+    
+    let results: Vec<TransliterationResult> = serde_json::from_slice(&buffer).unwrap();
+    assert_eq!(results.len(), texts.len());
+    for (result, text) in results.iter().zip(texts) {
+        assert_eq!(result.input, text);
+        assert_eq!(result.output, engine.transliterate(text));
+    }
+    
+    // Each element has the same shape as a single result
+    assert!(buffer.starts_with(r#"[{"input":"ami","output":"আমি"},"#.as_bytes()));
+    
+    let mut empty = Vec::new();
+    engine.batch_transliterate_json_stream(Vec::<String>::new(), &mut empty).unwrap();
+    assert_eq!(empty, b"[]");
+}
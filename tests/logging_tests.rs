@@ -0,0 +1,38 @@
+use std::sync::Mutex;
+
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use obadh_engine::Tokenizer;
+
+/// Logger that keeps every record's message for inspection
+struct CapturingLogger {
+    records: Mutex<Vec<(Level, String)>>,
+}
+
+impl Log for CapturingLogger {
+    fn enabled(&self, _metadata: &Metadata) -> bool {
+        true
+    }
+    
+    fn log(&self, record: &Record) {
+        self.records.lock().unwrap().push((record.level(), record.args().to_string()));
+    }
+    
+    fn flush(&self) {}
+}
+
+static LOGGER: CapturingLogger = CapturingLogger { records: Mutex::new(Vec::new()) };
+
+#[test]
+fn test_conjunct_emits_debug_record() {
+    log::set_logger(&LOGGER).unwrap();
+    log::set_max_level(LevelFilter::Debug);
+    
+    let tokenizer = Tokenizer::new();
+    tokenizer.tokenize_word("kk");
+    
+    let records = LOGGER.records.lock().unwrap();
+    assert!(records.iter().any(|(level, message)| *level == Level::Debug && message == "Formed conjunct 'k,,k'"));
+    
+    // Trace records are filtered by the max level
+    assert!(records.iter().all(|(level, _)| *level <= Level::Debug));
+}
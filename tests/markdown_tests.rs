@@ -0,0 +1,43 @@
+#![cfg(feature = "markdown")]
+
+use obadh_engine::ObadhEngine;
+
+#[test]
+fn test_markdown_keeps_code_and_links() {
+    let engine = ObadhEngine::new();
+    
+    let md = "# ami\n\
+              \n\
+              tumi *bhalo* acho? [amar bari](https://example.com/bari) dekho.\n\
+              \n\
+              ```rust\n\
+              let ami = \"tumi\";\n\
+              ```\n\
+              \n\
+              - `code` o gan\n\
+              - <https://example.com> ebong https://example.org/ami\n";
+    
+    let expected = "# আমি\n\
+                    \n\
+                    তুমি *ভাল* আছ? [আমার বারি](https://example.com/bari) দেখ।\n\
+                    \n\
+                    ```rust\n\
+                    let ami = \"tumi\";\n\
+                    ```\n\
+                    \n\
+                    - `code` অ গান\n\
+                    - <https://example.com> এবং https://example.org/ami\n";
+    
+    assert_eq!(engine.transliterate_markdown(md), expected);
+}
+
+#[test]
+fn test_markdown_keeps_escapes_and_entities() {
+    let engine = ObadhEngine::new();
+    
+    // Escaped characters and entities are kept as typed, not transliterated
+    assert_eq!(engine.transliterate_markdown("ami \\*tumi\\* &amp; kal"), "আমি \\*তুমি\\* &amp; কাল");
+    
+    assert_eq!(engine.transliterate_markdown("&copy; ami"), "&copy; আমি");
+    assert_eq!(engine.transliterate_markdown("ami \\# tumi"), "আমি \\# তুমি");
+}
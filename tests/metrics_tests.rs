@@ -0,0 +1,61 @@
+#![cfg(feature = "metrics")]
+
+use obadh_engine::engine::Transliterator;
+use obadh_engine::{ObadhEngine, StatsSnapshot};
+
+#[test]
+fn test_stats_totals() {
+    // Without the ASCII fallback 'Q' has no mapping
+    let engine = ObadhEngine::new().with_ascii_fallback(false);
+    assert_eq!(engine.stats(), StatsSnapshot::default());
+    
+    assert_eq!(engine.transliterate("ami"), "আমি");
+    assert_eq!(engine.transliterate("tumi Q"), "তুমি Q");
+    
+    let stats = engine.stats();
+    assert_eq!(stats.calls, 2);
+    assert_eq!(stats.chars_in, 3 + 6);
+    assert_eq!(stats.chars_out, 3 + 6);
+    assert_eq!(stats.unknown_fragments, 1);
+    assert_eq!(stats.cache_hits, 0);
+    
+    // A document memo counts its hits
+    let mut document = engine.document_transliterator();
+    document.transliterate("ami ami ami");
+    assert_eq!(engine.stats().calls, 3);
+    assert_eq!(engine.stats().cache_hits, 2);
+    
+    engine.reset_stats();
+    assert_eq!(engine.stats(), StatsSnapshot::default());
+}
+
+#[test]
+fn test_stats_across_threads() {
+    let engine = ObadhEngine::new();
+    
+    std::thread::scope(|scope| {
+        for _ in 0..4 {
+            scope.spawn(|| {
+                for _ in 0..25 {
+                    engine.transliterate("ami");
+                }
+            });
+        }
+    });
+    
+    let stats = engine.stats();
+    assert_eq!(stats.calls, 100);
+    assert_eq!(stats.chars_in, 300);
+}
+
+#[test]
+fn test_cloned_transliterator_counts_from_zero() {
+    let transliterator = Transliterator::new();
+    transliterator.transliterate("ami");
+    
+    let clone = transliterator.clone();
+    assert_eq!(clone.stats(), StatsSnapshot::default());
+    assert_eq!(clone.transliterate("tumi"), "তুমি");
+    assert_eq!(clone.stats().calls, 1);
+    assert_eq!(transliterator.stats().calls, 1);
+}
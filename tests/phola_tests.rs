@@ -122,4 +122,94 @@ fn test_vocalic_r_case() {
     let result = engine.transliterate("krri");
     println!("'krri' transliterates to: {}", result);
     assert_eq!(result, "কৃ");
-} 
\ No newline at end of file
+} 
+#[test]
+fn test_bo_fola_tokenization() {
+    let tokenizer = Tokenizer::new();
+    
+    // 'w' after a consonant is recognized as a bo-fola unit
+    let units = tokenizer.tokenize_word("biSw");
+    assert_eq!(units.len(), 3);
+    assert_eq!(units[1].text, "S");
+    assert_eq!(units[2].unit_type, PhoneticUnitType::BoFola);
+    assert_eq!(units[2].text, "w");
+    assert_eq!(units[2].position, 3);
+    
+    // A vowel after the bo-fola stays a separate unit
+    let units = tokenizer.tokenize_word("bishwas");
+    assert_eq!(units[2].unit_type, PhoneticUnitType::BoFola);
+    assert_eq!(units[3].unit_type, PhoneticUnitType::Vowel);
+    
+    // 'w' without a preceding consonant is not a bo-fola but the vowel ও
+    let units = tokenizer.tokenize_word("w");
+    assert_eq!(units[0].unit_type, PhoneticUnitType::Vowel);
+    let units = tokenizer.tokenize_word("aw");
+    assert_eq!(units[1].unit_type, PhoneticUnitType::Vowel);
+    
+    // Without the ASCII fallback it has no mapping
+    let units = Tokenizer::new().with_ascii_fallback(false).tokenize_word("w");
+    assert_eq!(units[0].unit_type, PhoneticUnitType::Unknown);
+}
+
+#[test]
+fn test_bo_fola_with_vowels() {
+    let engine = ObadhEngine::new();
+    
+    let examples = [
+        ("bishwo", "বিশ্ব"),     // terminator after bo-fola is the inherent vowel
+        ("bishwas", "বিশ্বাস"),  // dependent vowel sign after bo-fola
+        ("twi", "ত্বি"),
+    ];
+    
+    for (input, expected) in examples {
+        assert_eq!(engine.transliterate(input), expected);
+    }
+}
+
+#[test]
+fn test_disabling_pholas() {
+    let engine = ObadhEngine::new().with_ya_phala(false);
+    
+    // Without ya-phala, 'y' after a consonant stays a separate য়
+    assert_eq!(engine.transliterate("bidyaloy"), "বিদয়ালয়");
+    assert_eq!(engine.transliterate("sohy"), "সহয়");
+    // An explicit hasant still forms the conjunct
+    assert_eq!(engine.transliterate("d,,y"), "দ্য");
+    
+    // Without bo-fola, 'w' after a consonant is a separate ব
+    let engine = ObadhEngine::new().with_bo_fola(false);
+    assert_eq!(engine.transliterate("twa"), "তবা");
+    assert_eq!(engine.transliterate("biSw"), "বিশব");
+    
+    // Both are on by default
+    let engine = ObadhEngine::new();
+    assert_eq!(engine.transliterate("bidyaloy"), "বিদ্যালয়");
+    assert_eq!(engine.transliterate("twa"), "ত্বা");
+}
+
+#[test]
+fn test_word_initial_y() {
+    // With no consonant before it, a word-initial 'y' is য়, never a ya-phala,
+    // whatever comes before the word
+    let engine = ObadhEngine::new();
+    let examples = [
+        ("yamuna", "য়ামুনা"),
+        ("y", "য়"),
+        ("yog", "য়গ"),
+        ("ek yog", "এক য়গ"),
+        ("(yamuna)", "(য়ামুনা)"),
+        ("ya^", "য়াঁ"),
+    ];
+    
+    for (input, expected) in examples {
+        assert_eq!(engine.transliterate(input), expected, "input: {}", input);
+    }
+    
+    // Names spelt with য at the start
+    let engine = ObadhEngine::new().with_initial_ja(true);
+    assert_eq!(engine.transliterate("yamuna"), "যামুনা");
+    assert_eq!(engine.transliterate("ek yog"), "এক যগ");
+    // Only the first letter changes, and a ya-phala is unaffected
+    assert_eq!(engine.transliterate("yoy"), "যয়");
+    assert_eq!(engine.transliterate("bidyaloy"), "বিদ্যালয়");
+}
@@ -44,4 +44,40 @@ fn test_is_valid() {
     assert!(sanitizer.is_valid("abc123"));
     assert!(!sanitizer.is_valid("অআই"));
     assert!(!sanitizer.is_valid("Hello অ World"));
-} 
\ No newline at end of file
+} 
+#[test]
+fn test_validate_reports_all_invalid_chars() {
+    let sanitizer = Sanitizer::new();
+    
+    // Valid input yields no errors
+    assert!(sanitizer.validate("ami banglay gan gai").is_empty());
+    
+    // Three distinct invalid characters are all reported with their positions
+    let errors = sanitizer.validate("aঅb~c€");
+    assert_eq!(errors.len(), 3);
+    
+    assert_eq!(errors[0].character, 'অ');
+    assert_eq!(errors[0].position, 1);
+    assert_eq!(errors[1].character, '~');
+    assert_eq!(errors[1].position, 5);
+    assert_eq!(errors[2].character, '€');
+    assert_eq!(errors[2].position, 7);
+    
+    assert_eq!(errors[1].to_string(), "invalid character '~' at position 5");
+}
+
+#[test]
+fn test_default_allowed_chars() {
+    let allowed = Sanitizer::default_allowed_chars();
+    
+    for c in ['a', 'z', 'A', 'Z', '0', '9', ' ', ',', '^', ':', '`', '.'] {
+        assert!(allowed.contains(&c), "{:?} should be allowed", c);
+    }
+    assert!(!allowed.contains(&'অ') && !allowed.contains(&'\n') && !allowed.contains(&'~'));
+    
+    // The list is exactly what a new sanitizer accepts
+    let sanitizer = Sanitizer::new();
+    assert!(allowed.iter().all(|&c| sanitizer.is_valid(&c.to_string())));
+    assert!(sanitizer.is_valid(&allowed.iter().collect::<String>()));
+    assert_eq!(sanitizer.clean("ami\tআমি~tumi"), "amitumi");
+}
@@ -0,0 +1,77 @@
+//! Golden-file snapshots
+//!
+//! Each line of `tests/snapshots/<name>.txt` is transliterated with the default
+//! engine, and the lines are compared with `<name>.bengali` next to it. Run with
+//! `UPDATE_SNAPSHOTS=1 cargo test --test snapshot_tests` to write the current
+//! output to every `.bengali` file, then review the changes with `git diff`.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+use obadh_engine::ObadhEngine;
+
+/// A line-by-line diff of the expected and actual output, listing changed lines only
+fn line_diff(expected: &str, actual: &str) -> String {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+    let mut diff = String::new();
+    
+    for line in 0..expected.len().max(actual.len()) {
+        let (old, new) = (expected.get(line), actual.get(line));
+        if old != new {
+            diff.push_str(&format!("  line {}:\n", line + 1));
+            if let Some(old) = old {
+                diff.push_str(&format!("    - {}\n", old));
+            }
+            if let Some(new) = new {
+                diff.push_str(&format!("    + {}\n", new));
+            }
+        }
+    }
+    
+    diff
+}
+
+#[test]
+fn test_snapshots() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/snapshots");
+    let update = env::var_os("UPDATE_SNAPSHOTS").is_some();
+    let engine = ObadhEngine::new();
+    
+    let mut inputs: Vec<_> = fs::read_dir(&dir)
+        .expect("snapshot directory should be readable")
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .collect();
+    inputs.sort();
+    assert!(!inputs.is_empty(), "no snapshots in {}", dir.display());
+    
+    let mut failures = Vec::new();
+    for input in &inputs {
+        let actual: String = fs::read_to_string(input).unwrap()
+            .lines()
+            .map(|line| engine.transliterate(line) + "\n")
+            .collect();
+        let snapshot = input.with_extension("bengali");
+        
+        if update {
+            fs::write(&snapshot, &actual).unwrap();
+            continue;
+        }
+        
+        match fs::read_to_string(&snapshot) {
+            Ok(expected) if expected == actual => {},
+            Ok(expected) => failures.push(format!("{}:\n{}", snapshot.display(), line_diff(&expected, &actual))),
+            Err(_) => failures.push(format!("{}: missing", snapshot.display())),
+        }
+    }
+    
+    assert!(
+        failures.is_empty(),
+        "{} of {} snapshots differ (rerun with UPDATE_SNAPSHOTS=1 to accept the output):\n{}",
+        failures.len(),
+        inputs.len(),
+        failures.join("\n"),
+    );
+}
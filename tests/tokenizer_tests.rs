@@ -1,4 +1,5 @@
-use obadh_engine::{Tokenizer, TokenType};
+use obadh_engine::{CaseFoldProfile, ObadhEngine, PhoneticUnitType, Tokenizer, TokenType};
+use obadh_engine::wasm::TransliterationResult;
 
 #[test]
 fn test_text_tokenization() {
@@ -154,3 +155,392 @@ fn test_integration_with_engine() {
     }
 } 
 
+
+#[test]
+fn test_hyphenated_compound_tokenization() {
+    let tokenizer = Tokenizer::new();
+    
+    // A hyphen between word fragments keeps them as one word
+    let tokens = tokenizer.tokenize_text("kosmo-biggan");
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(tokens[0].token_type, TokenType::Word);
+    assert_eq!(tokens[0].content, "kosmo-biggan");
+    
+    // A hyphen that doesn't join two fragments is still punctuation
+    let tokens = tokenizer.tokenize_text("ami - tumi-");
+    assert_eq!(tokens.len(), 6);
+    assert_eq!(tokens[2].token_type, TokenType::Punctuation);
+    assert_eq!(tokens[2].content, "-");
+    assert_eq!(tokens[4].content, "tumi");
+    assert_eq!(tokens[5].token_type, TokenType::Punctuation);
+}
+
+#[test]
+fn test_vowel_hiatus_marker() {
+    let tokenizer = Tokenizer::new();
+    
+    // A dot between two vowels stays inside the word
+    let tokens = tokenizer.tokenize_text("kO.I ami.");
+    assert_eq!(tokens[0].content, "kO.I");
+    assert_eq!(tokens[0].token_type, TokenType::Word);
+    
+    // ...while a dot anywhere else is still punctuation
+    assert_eq!(tokens[2].content, "ami");
+    assert_eq!(tokens[3].token_type, TokenType::Punctuation);
+    
+    // The marker is consumed and keeps the vowels from combining
+    let units = tokenizer.tokenize_word("kO.I");
+    assert_eq!(units.len(), 2);
+    assert_eq!(units[0].text, "kO");
+    assert_eq!(units[1].text, "I");
+    assert_eq!(units[1].position, 3);
+}
+
+#[test]
+fn test_decimal_point_tokenization() {
+    let tokenizer = Tokenizer::new();
+    
+    // A dot between digits stays inside the number
+    let tokens = tokenizer.tokenize_text("3.5 .5 3.");
+    assert_eq!(tokens[0].content, "3.5");
+    assert_eq!(tokens[0].token_type, TokenType::Number);
+    assert_eq!(tokens[2].content, ".5");
+    assert_eq!(tokens[2].token_type, TokenType::Number);
+    assert_eq!(tokens[2].position, 4);
+    
+    // ...while a dot after the last digit ends the number
+    assert_eq!(tokens[4].content, "3");
+    assert_eq!(tokens[5].content, ".");
+    assert_eq!(tokens[5].token_type, TokenType::Punctuation);
+}
+
+#[test]
+fn test_pattern_introspection() {
+    use obadh_engine::PhoneticUnitType;
+    
+    let tokenizer = Tokenizer::new();
+    
+    let consonants = tokenizer.consonant_patterns();
+    assert!(consonants.contains(&"kh"));
+    assert!(consonants.contains(&"Sh"));
+    assert!(consonants.windows(2).all(|pair| pair[0] < pair[1]));
+    
+    let vowels = tokenizer.vowel_patterns();
+    assert!(vowels.contains(&"A"));
+    assert!(vowels.contains(&"OI"));
+    // 'o' is recognized as a terminating vowel special sequence instead
+    assert!(!vowels.contains(&"o"));
+    
+    let sequences = tokenizer.special_sequences();
+    assert!(sequences.contains(&("o", &PhoneticUnitType::TerminatingVowel)));
+    assert!(sequences.contains(&("rr", &PhoneticUnitType::SpecialForm)));
+    assert!(sequences.contains(&(",,", &PhoneticUnitType::ConsonantWithHasant)));
+}
+
+#[test]
+fn test_nasalization_and_visarga_flags() {
+    let tokenizer = Tokenizer::new();
+    
+    let units = tokenizer.tokenize_word("cha^d");
+    assert!(units[0].has_chandrabindu());
+    assert!(!units[0].has_visarga());
+    assert!(!units[1].has_chandrabindu());
+    
+    let units = tokenizer.tokenize_word("du:kho");
+    assert!(units.iter().all(|unit| !unit.has_chandrabindu()));
+    assert_eq!(units.iter().filter(|unit| unit.has_visarga()).count(), 1);
+    assert!(units[1].has_visarga());
+}
+
+#[test]
+fn test_token_iter() {
+    let tokenizer = Tokenizer::new();
+    
+    let mut tokens = tokenizer.token_iter("ami,, ,,k T``a cha^d: 3.5!");
+    let expected = [
+        ("ami,,", TokenType::Word, 0),
+        (" ", TokenType::Whitespace, 5),
+        (",", TokenType::Punctuation, 6),
+        (",", TokenType::Punctuation, 7),
+        ("k", TokenType::Word, 8),
+        (" ", TokenType::Whitespace, 9),
+        ("T``a", TokenType::Word, 10),
+        (" ", TokenType::Whitespace, 14),
+        ("cha^d:", TokenType::Word, 15),
+        (" ", TokenType::Whitespace, 21),
+        ("3.5", TokenType::Number, 22),
+        ("!", TokenType::Punctuation, 25),
+    ];
+    for (content, token_type, position) in expected {
+        let token = tokens.next().expect("missing token");
+        assert_eq!(token.content, content);
+        assert_eq!(token.token_type, token_type);
+        assert_eq!(token.position, position);
+    }
+    assert!(tokens.next().is_none());
+    
+    // Hyphens inside a word are part of it, other hyphens are punctuation
+    let tokens: Vec<_> = tokenizer.token_iter("kosmo-biggan - tumi-")
+        .map(|token| (token.content, token.token_type, token.position))
+        .collect();
+    assert_eq!(tokens, [
+        ("kosmo-biggan".to_string(), TokenType::Word, 0),
+        (" ".to_string(), TokenType::Whitespace, 12),
+        ("-".to_string(), TokenType::Punctuation, 13),
+        (" ".to_string(), TokenType::Whitespace, 14),
+        ("tumi".to_string(), TokenType::Word, 15),
+        ("-".to_string(), TokenType::Punctuation, 19),
+    ]);
+    
+    // Tokens are produced on demand, so a long text can be read in part
+    let long_text = "ami ".repeat(100_000);
+    let mut tokens = tokenizer.token_iter(&long_text);
+    let first: Vec<_> = tokens.by_ref().take(3).map(|token| (token.content, token.position)).collect();
+    assert_eq!(first, [("ami".to_string(), 0), (" ".to_string(), 3), ("ami".to_string(), 4)]);
+    // and picked up where it was left
+    let next = tokens.next().unwrap();
+    assert_eq!((next.content.as_str(), next.position), (" ", 7));
+    
+    // Searching stops at the first match
+    let number = tokenizer.token_iter("ami 12 tumi 34").find(|token| token.token_type == TokenType::Number).unwrap();
+    assert_eq!((number.content.as_str(), number.position), ("12", 4));
+}
+
+#[test]
+fn test_unit_queries() {
+    use obadh_engine::PhoneticUnit;
+    
+    let tokenizer = Tokenizer::new();
+    let syllables = |units: &[PhoneticUnit]| units.iter().filter(|unit| unit.is_syllable()).count();
+    
+    // No conjunct and no reph
+    let units = tokenizer.tokenize_word("amar");
+    assert!(!units.iter().any(PhoneticUnit::is_conjunct));
+    assert!(!units.iter().any(PhoneticUnit::has_reph));
+    assert_eq!(syllables(&units), 3);
+    
+    // A conjunct: বস্তু
+    let units = tokenizer.tokenize_word("bostu");
+    assert!(units.iter().any(PhoneticUnit::is_conjunct));
+    assert!(!units.iter().any(PhoneticUnit::has_reph));
+    assert_eq!(syllables(&units), 2);
+    
+    // Special-rule clusters are conjuncts too: ক্ষ
+    assert!(tokenizer.tokenize_word("kkh")[0].is_conjunct());
+    assert!(!tokenizer.tokenize_word("aya")[0].is_conjunct());
+    
+    // A reph: কর্ম, and a standalone one
+    let units = tokenizer.tokenize_word("korrmo");
+    assert!(units.iter().any(PhoneticUnit::has_reph));
+    assert!(!units.iter().any(PhoneticUnit::is_conjunct));
+    assert_eq!(syllables(&units), 2);
+    assert!(tokenizer.tokenize_word("rr")[0].has_reph());
+    
+    // Modifiers don't count as syllables: বাংলা, দুঃখ
+    assert_eq!(syllables(&tokenizer.tokenize_word("bangla")), 2);
+    assert_eq!(syllables(&tokenizer.tokenize_word("du:kho")), 2);
+}
+
+#[test]
+fn test_tokenize_only_matches_analysis() {
+    let engine = ObadhEngine::new();
+    let input = "ami kO.I, 3.5 biSwas-ghatok!";
+    
+    let expected = [
+        ("ami", TokenType::Word, 0),
+        (" ", TokenType::Whitespace, 3),
+        ("kO.I", TokenType::Word, 4),
+        (",", TokenType::Punctuation, 8),
+        (" ", TokenType::Whitespace, 9),
+        ("3.5", TokenType::Number, 10),
+        (" ", TokenType::Whitespace, 13),
+        ("biSwas-ghatok", TokenType::Word, 14),
+        ("!", TokenType::Punctuation, 27),
+    ];
+    
+    let tokens: Vec<_> = engine.tokenize_only(input).into_iter()
+        .map(|token| (token.content, token.token_type, token.position))
+        .collect();
+    let expected_tokens: Vec<_> = expected.iter()
+        .map(|(content, token_type, position)| (content.to_string(), token_type.clone(), *position))
+        .collect();
+    assert_eq!(tokens, expected_tokens);
+    
+    // The verbose analysis reports the same tokens
+    let analysis: Vec<_> = TransliterationResult::new(&engine, input, true).token_analysis.unwrap().into_iter()
+        .map(|analyzed| (analyzed.content, analyzed.r#type, analyzed.position))
+        .collect();
+    let expected_analysis: Vec<_> = expected.iter()
+        .map(|(content, token_type, position)| (content.to_string(), format!("{:?}", token_type), *position))
+        .collect();
+    assert_eq!(analysis, expected_analysis);
+    
+    // Case folding applies as it does for transliteration
+    let engine = ObadhEngine::new().with_case_folding(CaseFoldProfile::Simple);
+    assert_eq!(engine.tokenize_only("AMI")[0].content, "ami");
+}
+
+#[test]
+fn test_capital_nasal_disambiguation() {
+    let tokenizer = Tokenizer::new();
+    let texts = |word: &str| -> Vec<String> {
+        tokenizer.tokenize_word(word).into_iter().map(|unit| unit.text).collect()
+    };
+    
+    // Each capital nasal is one consonant unit, longest match first
+    assert_eq!(texts("N"), ["N"]);
+    assert_eq!(texts("Ng"), ["Ng"]);
+    assert_eq!(texts("NG"), ["NG"]);
+    assert_eq!(texts("NGo"), ["NGo"]);
+    
+    // Lowercase "ng" is the anusvara and never a consonant
+    assert_eq!(texts("ng"), ["ng"]);
+    assert_eq!(texts("rang"), ["ra", "ng"]);
+    
+    // Inside a cluster the capital nasal is still matched whole
+    assert_eq!(texts("bNg"), ["b,,Ng"]);
+    assert_eq!(texts("kaNGcon"), ["ka", "NG,,co", "n"]);
+    
+    let engine = ObadhEngine::new();
+    assert_eq!(engine.transliterate("N Ng NG ng"), "ণ ঙ ঞ ং");
+    assert_eq!(engine.transliterate("bNg"), "ব্ঙ");
+    assert_eq!(engine.transliterate("kaNGcon"), "কাঞ্চন");
+}
+
+#[test]
+fn test_special_sequence_order_is_deterministic() {
+    // "kkh" could be matched as the special rule (ক্ষ) or as "k" + "kh", and
+    // "aya" as the special rule or as "a" + "ya"; the longest sequence wins
+    let expected: Vec<String> = vec!["kkh".into(), "aya".into()];
+    
+    // Every tokenizer has its own hash map seeds, so build a fresh one each time
+    for _ in 0..32 {
+        let tokenizer = Tokenizer::new();
+        let texts: Vec<String> = ["kkh", "aya"].iter()
+            .flat_map(|word| tokenizer.tokenize_word(word))
+            .map(|unit| unit.text)
+            .collect();
+        assert_eq!(texts, expected);
+    }
+}
+
+#[test]
+fn test_consonant_pattern_order_is_deterministic() {
+    // Equal-length consonant patterns are tried alphabetically, so tokenizing
+    // the same words gives the same units whichever tokenizer does it
+    let words = ["chhaTa", "kShoNik", "bNGgo", "RhakRh", "Shobdho", "jhNgkar"];
+    let units = |tokenizer: &Tokenizer| -> Vec<(String, PhoneticUnitType)> {
+        words.iter()
+            .flat_map(|word| tokenizer.tokenize_word(word))
+            .map(|unit| (unit.text, unit.unit_type))
+            .collect()
+    };
+    
+    let expected = units(&Tokenizer::new());
+    for _ in 0..32 {
+        assert_eq!(units(&Tokenizer::new()), expected);
+    }
+}
+
+#[test]
+fn test_visarga_placement() {
+    let tokenizer = Tokenizer::new();
+    let units = |word: &str| -> Vec<(String, usize)> {
+        tokenizer.tokenize_word(word).into_iter().map(|unit| (unit.text, unit.position)).collect()
+    };
+    
+    // Mid-word, the visarga is its own unit right after the unit it follows
+    assert_eq!(units("du:kho"), [("du".to_string(), 0), (":".to_string(), 2), ("kho".to_string(), 3)]);
+    assert!(tokenizer.tokenize_word("du:kho")[1].has_visarga());
+    
+    // A consonant after it never joins a conjunct across it
+    assert_eq!(units("k:k"), [("k".to_string(), 0), (":".to_string(), 1), ("k".to_string(), 2)]);
+    
+    // At the end of a word, its position is its place in the input, even after a conjunct
+    assert_eq!(units("na:"), [("na".to_string(), 0), (":".to_string(), 2)]);
+    assert_eq!(units("kk:"), [("k,,k".to_string(), 0), (":".to_string(), 2)]);
+    
+    let engine = ObadhEngine::new();
+    assert_eq!(engine.transliterate("du:kho"), "দুঃখ");
+    assert_eq!(engine.transliterate("ami na: bolechi"), "আমি নাঃ বলেছি");
+    assert_eq!(engine.transliterate("pun:pun:"), "পুনঃপুনঃ");
+}
+
+#[test]
+fn test_roman_display() {
+    let tokenizer = Tokenizer::new();
+    let display = |word: &str| -> Vec<(String, PhoneticUnitType)> {
+        tokenizer.tokenize_word(word).into_iter().map(|unit| (unit.roman_display(), unit.unit_type)).collect()
+    };
+    
+    assert_eq!(display("kk"), [("kk".to_string(), PhoneticUnitType::Conjunct)]);
+    assert_eq!(display("rrm"), [("rrm".to_string(), PhoneticUnitType::RephOverConsonant)]);
+    assert_eq!(display("sTro"), [("sTro".to_string(), PhoneticUnitType::ConjunctWithTerminator)]);
+    assert_eq!(display("byakti"), [
+        ("bya".to_string(), PhoneticUnitType::ConjunctWithVowel),
+        ("kti".to_string(), PhoneticUnitType::ConjunctWithVowel),
+    ]);
+    
+    // An explicit trailing hasant is what the user typed
+    assert_eq!(display("k,,"), [("k,,".to_string(), PhoneticUnitType::ConsonantWithHasant)]);
+    assert_eq!(display(",,"), [(",,".to_string(), PhoneticUnitType::ConsonantWithHasant)]);
+}
+
+#[test]
+fn test_protected_terms() {
+    let tokenizer = Tokenizer::new().with_protected_terms(vec!["iPhone".to_string()]);
+    let types: Vec<TokenType> = tokenizer.tokenize_text("amar iPhone").into_iter().map(|token| token.token_type).collect();
+    assert_eq!(types, [TokenType::Word, TokenType::Whitespace, TokenType::Foreign]);
+    
+    let engine = ObadhEngine::new().with_protected_terms(vec!["iPhone".to_string(), "Google".to_string()]);
+    assert_eq!(engine.transliterate("amar iPhone"), "আমার iPhone");
+    assert_eq!(engine.transliterate("Google, ami"), "Google, আমি");
+    
+    // Only whole words are protected, and case matters unless told otherwise
+    assert_ne!(engine.transliterate("iPhoner"), "iPhoner");
+    assert_ne!(engine.transliterate("iphone"), "iphone");
+    let engine = engine.with_protected_terms_ignore_case(true);
+    assert_eq!(engine.transliterate("amar iphone"), "আমার iphone");
+}
+
+#[test]
+fn test_protected_terms_with_case_folding() {
+    // Terms are matched before folding and kept as typed
+    let engine = ObadhEngine::new()
+        .with_case_folding(CaseFoldProfile::Simple)
+        .with_protected_terms(vec!["iPhone".to_string()]);
+    assert_eq!(engine.transliterate("Amar iPhone, Tomar."), "আমার iPhone, তমার।");
+    assert!(!engine.transliterate("Amar IPHONE").contains("IPHONE"));
+    
+    let engine = engine.with_protected_terms_ignore_case(true);
+    assert_eq!(engine.transliterate("Amar IPHONE"), "আমার IPHONE");
+}
+
+#[test]
+fn test_max_conjunct_length() {
+    let clusters = |tokenizer: &Tokenizer, word: &str| -> Vec<(String, PhoneticUnitType)> {
+        tokenizer.tokenize_word(word).into_iter().map(|unit| (unit.text, unit.unit_type)).collect()
+    };
+    
+    // Unlimited by default
+    assert_eq!(clusters(&Tokenizer::new(), "kstrp"), [("k,,s,,t,,r,,p".to_string(), PhoneticUnitType::Conjunct)]);
+    
+    // The first three consonants end with a hasant and the rest form a second cluster
+    let tokenizer = Tokenizer::new().with_max_conjunct_length(3);
+    assert_eq!(clusters(&tokenizer, "kstrp"), [
+        ("k,,s,,t,,".to_string(), PhoneticUnitType::Conjunct),
+        ("r,,p".to_string(), PhoneticUnitType::Conjunct),
+    ]);
+    assert_eq!(clusters(&tokenizer, "kstrpa")[1], ("r,,pa".to_string(), PhoneticUnitType::ConjunctWithVowel));
+    
+    // The boundary is a visible hasant, so the clusters aren't joined again
+    let engine = ObadhEngine::new().with_max_conjunct_length(3);
+    assert_eq!(engine.transliterate("kstrp"), "ক্স্ত্\u{200C}র্প");
+    assert_eq!(engine.transliterate("songskriti"), ObadhEngine::new().transliterate("songskriti"));
+    assert_eq!(ObadhEngine::new().transliterate("kstrp"), "ক্স্ত্র্প");
+    
+    // A vowel after a typed hasant stays independent rather than a vowel sign
+    let engine = ObadhEngine::new().with_max_conjunct_length(2);
+    assert_eq!(engine.transliterate("kst,,a"), "ক্স্\u{200C}ত্আ");
+    assert_eq!(engine.transliterate("ksta"), "ক্স্\u{200C}তা");
+}
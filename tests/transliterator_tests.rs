@@ -0,0 +1,692 @@
+use obadh_engine::engine::Transliterator;
+use obadh_engine::{PhoneticUnitType, UnmappableError, VowelForm, Warning, WarningKind};
+
+#[test]
+fn test_annotate_matches_transliterate() {
+    let transliterator = Transliterator::new();
+    
+    let inputs = ["ami banglay gan gai", "biSwas 12, korrmo.", "kk", ""];
+    
+    for input in inputs {
+        let annotations = transliterator.annotate(input);
+        let joined: String = annotations.iter().map(|a| a.bengali.as_str()).collect();
+        assert_eq!(joined, transliterator.transliterate(input));
+        
+        // The Roman fragments of a valid input cover the whole text
+        let roman: String = annotations.iter().map(|a| a.roman.as_str()).collect();
+        assert_eq!(roman.replace(",,", ""), input.replace(",,", ""));
+    }
+}
+
+#[test]
+fn test_annotate_units() {
+    let transliterator = Transliterator::new();
+    
+    let annotations = transliterator.annotate("biSw 5");
+    
+    // "bi", "S", "w" per unit, then whitespace and the number as whole tokens
+    assert_eq!(annotations.len(), 5);
+    
+    assert_eq!(annotations[0].roman, "bi");
+    assert_eq!(annotations[0].bengali, "বি");
+    assert_eq!(annotations[0].unit_type, PhoneticUnitType::ConsonantWithVowel);
+    
+    assert_eq!(annotations[1].roman, "S");
+    assert_eq!(annotations[1].bengali, "শ");
+    
+    assert_eq!(annotations[2].roman, "w");
+    assert_eq!(annotations[2].bengali, "্ব");
+    assert_eq!(annotations[2].unit_type, PhoneticUnitType::BoFola);
+    
+    assert_eq!(annotations[3].unit_type, PhoneticUnitType::Symbol);
+    assert_eq!(annotations[4].bengali, "৫");
+    assert_eq!(annotations[4].unit_type, PhoneticUnitType::Numeral);
+}
+
+#[test]
+fn test_empty_and_whitespace_input() {
+    let transliterator = Transliterator::new();
+    
+    assert_eq!(transliterator.transliterate_lenient(""), "");
+    assert_eq!(transliterator.transliterate_lenient(" \n\t "), " \n\t ");
+    assert!(transliterator.annotate("").is_empty());
+}
+
+#[test]
+fn test_transliterate_into_reuses_buffer() {
+    let transliterator = Transliterator::new();
+    let mut buffer = String::from("stale contents");
+    
+    for input in ["ami banglay gan gai", "", "  ", "kk ,,k", "dam 35 taka", "ami-tumi", "\u{0995}"] {
+        transliterator.transliterate_into(input, &mut buffer);
+        assert_eq!(buffer, transliterator.transliterate(input), "input: {:?}", input);
+    }
+}
+
+#[test]
+fn test_supported_mappings() {
+    let transliterator = Transliterator::new();
+    let mappings = transliterator.supported_mappings();
+    
+    assert!(!mappings.is_empty());
+    assert!(mappings.windows(2).all(|pair| pair[0] < pair[1]));
+    
+    let contains = |roman: &str, bengali: &str| {
+        mappings.iter().any(|(r, b)| r == roman && b == bengali)
+    };
+    assert!(contains("kh", "খ"));
+    // Vowels are listed with both forms
+    assert!(contains("i", "ই"));
+    assert!(contains("i", "ি"));
+    // Diacritics, symbols, numerals and special rules
+    assert!(contains(",,", "্"));
+    assert!(contains(".", "।"));
+    assert!(contains("5", "৫"));
+    assert!(contains("kkh", "ক্ষ"));
+}
+
+#[test]
+fn test_transliterate_capped() {
+    let transliterator = Transliterator::new();
+    let input = "ami bikkhobh songskriti biSw jotno";
+    let full = transliterator.transliterate(input);
+    
+    // A cap above the output size keeps everything
+    assert_eq!(transliterator.transliterate_capped(input, full.len()), (full.clone(), false));
+    
+    for cap in 0..full.len() {
+        let (output, truncated) = transliterator.transliterate_capped(input, cap);
+        assert!(truncated);
+        assert!(output.len() <= cap);
+        assert!(full.starts_with(&output));
+        // Never cut inside a conjunct
+        assert!(!output.ends_with('্'), "cap {} gave {}", cap, output);
+        assert!(!full[output.len()..].starts_with('্'), "cap {} gave {}", cap, output);
+    }
+    
+    // "jotno" ends in the conjunct ত্ন, which is dropped whole rather than split
+    let (output, _) = transliterator.transliterate_capped("jotno", "জত্".len() + 1);
+    assert_eq!(output, "জ");
+}
+
+#[test]
+fn test_transliterate_capped_matches_transliterate() {
+    let input = "ami bikkhobh songskriti, biSw jotno 5";
+    let engines = [
+        Transliterator::new(),
+        Transliterator::new().with_interlinear(true),
+    ];
+    
+    for transliterator in &engines {
+        assert_eq!(
+            transliterator.transliterate_capped(input, usize::MAX),
+            (transliterator.transliterate(input), false)
+        );
+    }
+    
+    // A truncated interlinear output is still a prefix of the full output
+    let full = engines[1].transliterate(input);
+    let (output, truncated) = engines[1].transliterate_capped(input, 12);
+    assert!(truncated);
+    assert!(full.starts_with(&output));
+    assert!(output.starts_with("ami ("));
+}
+
+#[test]
+fn test_annotate_and_capped_with_typography() {
+    use obadh_engine::TypographyOptions;
+    
+    let transliterator = Transliterator::new().with_typography(
+        TypographyOptions::default().with_em_dash(true).with_ellipsis(true).with_curly_quotes(true)
+    );
+    let input = "se bollo -- \"ami jabo...\"";
+    let full = transliterator.transliterate(input);
+    assert_eq!(full, "সে বল্ল — “আমি জাব…”");
+    
+    let annotated: String = transliterator.annotate(input).into_iter().map(|a| a.bengali).collect();
+    assert_eq!(annotated, full);
+    assert_eq!(transliterator.transliterate_capped(input, usize::MAX), (full.clone(), false));
+    
+    let (output, truncated) = transliterator.transliterate_capped(input, "সে বল্ল —".len());
+    assert!(truncated);
+    assert_eq!(output, "সে বল্ল —");
+}
+
+#[test]
+fn test_annotate_and_capped_with_whitespace_collapse() {
+    let input = "ami\t\t tumi  \n\n  amar bari ";
+    let engines = [
+        Transliterator::new().with_whitespace_collapse(true),
+        Transliterator::new().with_whitespace_collapse(true).with_newlines_kept(true),
+    ];
+    
+    for transliterator in &engines {
+        let full = transliterator.transliterate(input);
+        let annotated: String = transliterator.annotate(input).into_iter().map(|a| a.bengali).collect();
+        assert_eq!(annotated, full);
+        assert_eq!(transliterator.transliterate_capped(input, usize::MAX), (full, false));
+    }
+    
+    assert_eq!(engines[0].transliterate_capped(input, "আমি তুমি ".len()), ("আমি তুমি ".to_string(), true));
+    assert_eq!(engines[1].transliterate_capped(input, "আমি তুমি\n".len()), ("আমি তুমি\n".to_string(), true));
+}
+
+#[test]
+fn test_unmappable_fragments() {
+    let transliterator = Transliterator::new();
+    
+    // Clean input maps completely
+    assert!(transliterator.is_fully_mappable("ami banglay gan gai, 123."));
+    assert!(transliterator.unmappable_fragments("ami banglay gan gai").is_empty());
+    
+    // A stray '#' has no mapping
+    assert!(!transliterator.is_fully_mappable("ami #an gai"));
+    assert_eq!(transliterator.unmappable_fragments("ami #an gai"), vec![("#".to_string(), 4)]);
+    
+    // Positions account for hyphens inside a word
+    assert_eq!(transliterator.unmappable_fragments("ami-tumi-#"), vec![("#".to_string(), 9)]);
+    
+    // Characters the sanitizer rejects are reported too
+    assert_eq!(transliterator.unmappable_fragments("ami é"), vec![("é".to_string(), 4)]);
+}
+
+#[test]
+fn test_transliterate_strict() {
+    let transliterator = Transliterator::new();
+    
+    // Clean input gives the same output as the lenient path
+    assert_eq!(transliterator.transliterate_strict("ami banglay gan gai, 123."),
+               Ok(transliterator.transliterate("ami banglay gan gai, 123.")));
+    
+    // A '#' inside a word has no place in Bengali text
+    let error = transliterator.transliterate_strict("ami#tumi").unwrap_err();
+    assert_eq!(error.fragments, vec![("#".to_string(), 3)]);
+    assert_eq!(error.to_string(), "no Bengali mapping for '#' at position 3");
+    
+    // Every fragment is listed, in input order
+    let error = transliterator.transliterate_strict("@ ami #").unwrap_err();
+    assert_eq!(error.fragments, vec![("@".to_string(), 0), ("#".to_string(), 6)]);
+    
+    // An error built without fragments still displays
+    let error = UnmappableError { fragments: Vec::new() };
+    assert_eq!(error.to_string(), "no Bengali mapping for part of the input");
+}
+
+#[test]
+fn test_batch_try_transliterate() {
+    let transliterator = Transliterator::new();
+    
+    let results = transliterator.batch_try_transliterate(&["ami", "ami#tumi"]);
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0], Ok("আমি".to_string()));
+    assert_eq!(results[1].as_ref().unwrap_err().fragments, vec![("#".to_string(), 3)]);
+}
+
+#[test]
+fn test_transliterate_with_warnings() {
+    let transliterator = Transliterator::new();
+    
+    // Clean input has no warnings
+    let (output, warnings) = transliterator.transliterate_with_warnings("ami banglay gan gai");
+    assert_eq!(output, transliterator.transliterate("ami banglay gan gai"));
+    assert!(warnings.is_empty());
+    
+    // A stray character is passed through with a single warning
+    let (output, warnings) = transliterator.transliterate_with_warnings("ami # tumi");
+    assert_eq!(output, transliterator.transliterate("ami # tumi"));
+    assert_eq!(warnings, vec![Warning {
+        kind: WarningKind::UnknownFragment,
+        fragment: "#".to_string(),
+        position: 4,
+    }]);
+    
+    // A conjunct with a chandrabindu can't be rendered and is kept as typed
+    let fallback = |position| vec![Warning {
+        kind: WarningKind::Fallback,
+        fragment: "k,,k^".to_string(),
+        position,
+    }];
+    let (output, warnings) = transliterator.transliterate_with_warnings("ami kk^");
+    assert_eq!(output, transliterator.transliterate("ami kk^"));
+    assert_eq!(warnings, fallback(4));
+    
+    // Hyphens and syllable markers don't shift the unit that fell back
+    let transliterator = Transliterator::new().with_syllable_markers(Some('·'));
+    let (output, warnings) = transliterator.transliterate_with_warnings("bak-amikk^");
+    assert_eq!(output, "বাক-আ·মিk,,k^");
+    assert_eq!(warnings, fallback(7));
+}
+
+#[test]
+fn test_validate_mappings() {
+    use std::collections::HashMap;
+    use obadh_engine::MappingConflict;
+    
+    let transliterator = Transliterator::new();
+    let overrides = |keys: &[&str]| -> HashMap<String, String> {
+        keys.iter().map(|key| (key.to_string(), "?".to_string())).collect()
+    };
+    
+    // A sequence nothing else starts with is fine
+    assert!(transliterator.validate_mappings(&overrides(&["qx"])).is_empty());
+    
+    // "kha" would read "kh" + "a" as one unit
+    assert_eq!(transliterator.validate_mappings(&overrides(&["kha"])), vec![
+        MappingConflict::PrefixCollision { key: "kha".to_string(), other: "kh".to_string() },
+    ]);
+    
+    // Built-in sequences are shadowed, and overrides collide with each other
+    assert_eq!(transliterator.validate_mappings(&overrides(&["kkh", "qx", "qxz"])), vec![
+        MappingConflict::ShadowsBuiltin { key: "kkh".to_string() },
+        MappingConflict::PrefixCollision { key: "qx".to_string(), other: "qxz".to_string() },
+        MappingConflict::PrefixCollision { key: "qxz".to_string(), other: "qx".to_string() },
+    ]);
+}
+
+#[test]
+fn test_open_and_closed_syllables() {
+    let transliterator = Transliterator::new();
+    let syllables = |word: &str| -> Vec<(String, bool)> {
+        transliterator.syllables(word).into_iter().map(|syllable| (syllable.bengali.clone(), syllable.is_open())).collect()
+    };
+    
+    // কা ends in its vowel, কক্ in a hasant
+    assert_eq!(syllables("ka"), [("কা".to_string(), true)]);
+    assert_eq!(syllables("kok,,"), [("কক্".to_string(), false)]);
+    let closed = &transliterator.syllables("kok,,")[0];
+    assert!(closed.is_closed());
+    
+    // A consonant after the vowel closes the syllable, as does the anusvara
+    assert_eq!(syllables("amar"), [("আ".to_string(), true), ("মার".to_string(), false)]);
+    assert_eq!(syllables("bangla"), [("বাং".to_string(), false), ("লা".to_string(), true)]);
+    
+    // A conjunct alone carries the inherent vowel
+    assert_eq!(syllables("kk"), [("ক্ক".to_string(), true)]);
+}
+
+#[test]
+fn test_syllable_pairs() {
+    let transliterator = Transliterator::new();
+    let pair = |roman: &str, bengali: &str| (roman.to_string(), bengali.to_string());
+    
+    assert_eq!(transliterator.syllable_pairs("bhalobasha"),
+               vec![pair("bha", "ভা"), pair("lo", "ল"), pair("ba", "বা"), pair("sha", "শা")]);
+    
+    // An implicit conjunct keeps the Roman text as typed
+    assert_eq!(transliterator.syllable_pairs("bidyaloy"),
+               vec![pair("bi", "বি"), pair("dya", "দ্যা"), pair("loy", "লয়")]);
+}
+
+#[test]
+fn test_script_detection() {
+    // Pure Bengali, including punctuation and Bengali digits
+    assert!(Transliterator::is_bengali("আমি বাংলায় গান গাই।"));
+    assert!(Transliterator::is_bengali("ক্ষমা, ১২৩"));
+    assert!(Transliterator::contains_bengali("আমি"));
+    
+    // Pure Latin
+    assert!(!Transliterator::is_bengali("ami banglay gan gai"));
+    assert!(!Transliterator::contains_bengali("ami banglay gan gai"));
+    
+    // Mixed scripts contain Bengali but are not Bengali
+    assert!(!Transliterator::is_bengali("আমি ami"));
+    assert!(!Transliterator::is_bengali("আমি 123"));
+    assert!(Transliterator::contains_bengali("ami আমি"));
+    
+    // Nothing to detect
+    assert!(!Transliterator::is_bengali(""));
+    assert!(!Transliterator::is_bengali(", ."));
+}
+
+#[test]
+fn test_transliterate_pairs() {
+    let transliterator = Transliterator::new();
+    
+    for input in ["ami banglay gan gai.", "du:kho, 12 $", "kosmo-biggan  tumi?", ""] {
+        let pairs = transliterator.transliterate_pairs(input);
+        let roman: String = pairs.iter().map(|(roman, _)| roman.as_str()).collect();
+        let bengali: String = pairs.iter().map(|(_, bengali)| bengali.as_str()).collect();
+        
+        assert_eq!(roman, input);
+        assert_eq!(bengali, transliterator.transliterate(input));
+    }
+    
+    let pairs = transliterator.transliterate_pairs("ami, tumi.");
+    let expected = [("ami", "আমি"), (",", ","), (" ", " "), ("tumi", "তুমি"), (".", "।")];
+    assert_eq!(pairs, expected.map(|(roman, bengali)| (roman.to_string(), bengali.to_string())));
+}
+
+#[test]
+fn test_truncate_clusters() {
+    let transliterator = Transliterator::new();
+    
+    // The reph, the conjunct ক্ষ and its vowel sign are one cluster, ত্র another
+    let word = "র্ক্ষেত্র";
+    assert_eq!(transliterator.truncate_clusters(word, 1), "র্ক্ষে");
+    assert_eq!(transliterator.truncate_clusters(word, 2), word);
+    assert_eq!(transliterator.truncate_clusters(word, 0), "");
+    
+    // Vowel signs and the chandrabindu stay with their consonant
+    assert_eq!(transliterator.truncate_clusters("চাঁদ উঠেছে", 1), "চাঁ");
+    assert_eq!(transliterator.truncate_clusters("চাঁদ উঠেছে", 3), "চাঁদ ");
+    
+    // A visible hasant still joins, and the result never ends in a hasant
+    let joined = transliterator.truncate_clusters("ক্\u{200C}ষমা", 1);
+    assert_eq!(joined, "ক্\u{200C}ষ");
+    for n in 0..8 {
+        let preview = transliterator.truncate_clusters("শিক্ষার্থী ব্যক্তি", n);
+        assert!(!preview.ends_with('্'), "{} clusters gave {}", n, preview);
+        assert!(!preview.ends_with('\u{200C}'), "{} clusters gave {}", n, preview);
+    }
+}
+
+#[test]
+fn test_syllable_markers() {
+    let transliterator = Transliterator::new().with_syllable_markers(Some('·'));
+    
+    // A consonant without a vowel closes the syllable before it
+    assert_eq!(transliterator.transliterate("amar"), "আ·মার");
+    assert_eq!(transliterator.transliterate("bhalobasi"), "ভা·ল·বা·সি");
+    assert_eq!(transliterator.transliterate("ami tumi"), "আ·মি তু·মি");
+    
+    // Never inside a conjunct, including the special forms and a reph
+    assert_eq!(transliterator.transliterate("bikkhobh"), "বি·ক্ষভ");
+    assert_eq!(transliterator.transliterate("songskriti"), "সং·স্ক্রি·তি");
+    assert_eq!(transliterator.transliterate("sUrrzo"), "সূ·র্য");
+    for word in ["bikkhobh", "songskriti", "sUrrzo", "biggan", "porikkha"] {
+        let marked = transliterator.transliterate(word);
+        assert!(!marked.contains("্·") && !marked.contains("·্"), "{} gave {}", word, marked);
+        assert_eq!(marked.replace('·', ""), Transliterator::new().transliterate(word));
+    }
+    
+    // Annotations still concatenate to the output
+    let annotated: String = transliterator.annotate("bhalobasi").into_iter().map(|a| a.bengali).collect();
+    assert_eq!(annotated, transliterator.transliterate("bhalobasi"));
+    
+    assert_eq!(Transliterator::new().with_syllable_markers(None).transliterate("amar"), "আমার");
+}
+
+#[test]
+fn test_explicit_inherent_vowel() {
+    // Off by default, with the normal output
+    assert_eq!(Transliterator::new().transliterate("komol"), "কমল");
+    assert_eq!(Transliterator::new().with_explicit_inherent_vowel(false).transliterate("komol"), "কমল");
+    
+    let transliterator = Transliterator::new().with_explicit_inherent_vowel(true);
+    assert_eq!(transliterator.transliterate("komol"), "ক(অ)ম(অ)ল(অ)");
+    assert_eq!(transliterator.transliterate("bon"), "ব(অ)ন(অ)");
+    
+    // Consonants with a vowel sign or hasant, or inside a conjunct, carry no inherent vowel
+    assert_eq!(transliterator.transliterate("ami"), "আমি");
+    assert_eq!(transliterator.transliterate("bikkhobh"), "বিক্ষ(অ)ভ(অ)");
+    assert_eq!(transliterator.transliterate("songskriti"), "স(অ)ংস্ক্রিতি");
+    assert_eq!(transliterator.transliterate("ami komol khai."), "আমি ক(অ)ম(অ)ল(অ) খাই।");
+}
+
+#[test]
+fn test_annotate_and_capped_with_explicit_inherent_vowel() {
+    let input = "komol bikkhobh songskriti, ami jabo";
+    let engines = [
+        Transliterator::new().with_explicit_inherent_vowel(true),
+        Transliterator::new().with_explicit_inherent_vowel(true).with_syllable_markers(Some('·')),
+    ];
+    
+    for transliterator in &engines {
+        let full = transliterator.transliterate(input);
+        let annotated: String = transliterator.annotate(input).into_iter().map(|a| a.bengali).collect();
+        assert_eq!(annotated, full);
+        assert_eq!(transliterator.transliterate_capped(input, usize::MAX), (full, false));
+    }
+    
+    // The marker is reported with the consonant it follows
+    let annotations = engines[0].annotate("komol");
+    let bengali: Vec<&str> = annotations.iter().map(|a| a.bengali.as_str()).collect();
+    assert_eq!(bengali, ["ক(অ)", "ম(অ)", "ল(অ)"]);
+}
+
+#[test]
+fn test_annotate_and_capped_with_latin_letters() {
+    use obadh_engine::Script;
+    
+    let transliterator = Transliterator::new().with_letter_script(Script::Latin);
+    let input = "ami 12 Ta gan gai.";
+    let full = transliterator.transliterate(input);
+    assert_eq!(full, "ami 12 Ta gan gai।");
+    
+    let annotations = transliterator.annotate(input);
+    let annotated: String = annotations.iter().map(|a| a.bengali.as_str()).collect();
+    assert_eq!(annotated, full);
+    assert_eq!(annotations[0].roman, "ami");
+    assert_eq!(annotations[0].bengali, "ami");
+    assert_eq!(annotations[0].unit_type, PhoneticUnitType::Unknown);
+    
+    assert_eq!(transliterator.transliterate_capped(input, usize::MAX), (full, false));
+    assert_eq!(transliterator.transliterate_capped(input, 6), ("ami 12".to_string(), true));
+}
+
+#[test]
+fn test_cluster_to_roman() {
+    let transliterator = Transliterator::new();
+    let roman = |cluster: &str| transliterator.cluster_to_roman(cluster);
+    
+    assert_eq!(roman("ক").as_deref(), Some("ko"));
+    assert_eq!(roman("কি").as_deref(), Some("ki"));
+    assert_eq!(roman("ক্ক").as_deref(), Some("kk"));
+    assert_eq!(roman("র্ক").as_deref(), Some("rrk"));
+    
+    // Special forms, the ya-phala, independent vowels and the modifiers
+    assert_eq!(roman("ক্ষ").as_deref(), Some("kkh"));
+    assert_eq!(roman("জ্ঞা").as_deref(), Some("gga"));
+    assert_eq!(roman("ব্যা").as_deref(), Some("bya"));
+    assert_eq!(roman("আ").as_deref(), Some("a"));
+    assert_eq!(roman("চাঁ").as_deref(), Some("ca^"));
+    assert_eq!(roman("সং").as_deref(), Some("song"));
+    assert_eq!(roman("ৎ").as_deref(), Some("T``"));
+    
+    // The lowercase, then the shortest input is preferred
+    assert_eq!(roman("জ").as_deref(), Some("jo"));
+    assert_eq!(roman("শ").as_deref(), Some("sho"));
+    
+    // Anything but a single cluster has no inverse
+    for text in ["", "কখ", "ি", "ami", "ক্"] {
+        assert_eq!(roman(text), None, "{}", text);
+    }
+    
+    // Every result transliterates back to its cluster
+    for cluster in ["ক", "কি", "ক্ক", "র্ক", "ক্ষ", "স্ত্র", "র্য", "ত্রি"] {
+        let input = roman(cluster).unwrap_or_else(|| panic!("no inverse for {}", cluster));
+        assert_eq!(transliterator.transliterate(&input), cluster);
+    }
+}
+
+#[test]
+fn test_document_transliterator_memoizes_words() {
+    use obadh_engine::DocumentTransliterator;
+    
+    let transliterator = Transliterator::new();
+    let mut document = DocumentTransliterator::new(&transliterator);
+    
+    let first = document.transliterate("ami gan gai, ami gan shuni.");
+    assert_eq!(first, transliterator.transliterate("ami gan gai, ami gan shuni."));
+    // The second "ami" and "gan" are served from the memo
+    assert_eq!(document.cache_hits(), 2);
+    assert_eq!(document.cached_words(), 4);
+    
+    // The memo lasts for the whole document
+    assert_eq!(document.transliterate("gan"), "গান");
+    assert_eq!(document.cache_hits(), 3);
+    
+    // A new document starts with an empty memo
+    let document = DocumentTransliterator::new(&transliterator);
+    assert_eq!(document.cache_hits(), 0);
+    assert_eq!(document.cached_words(), 0);
+}
+
+#[test]
+fn test_vowel_sign() {
+    let transliterator = Transliterator::new();
+    
+    assert_eq!(transliterator.vowel_sign("A").as_deref(), Some("\u{25CC}া"));
+    assert_eq!(transliterator.vowel_sign("i").as_deref(), Some("◌ি"));
+    assert_eq!(transliterator.vowel_sign("OU").as_deref(), Some("◌ৌ"));
+    assert_eq!(transliterator.vowel_sign("rri").as_deref(), Some("◌ৃ"));
+    
+    // The inherent vowel has no visible sign
+    assert_eq!(transliterator.vowel_sign("o"), None);
+    assert_eq!(transliterator.vowel_sign("k"), None);
+    assert_eq!(transliterator.vowel_sign(""), None);
+}
+
+#[test]
+fn test_format_number() {
+    use obadh_engine::NumberFormatOptions;
+    
+    let transliterator = Transliterator::new();
+    let format = |value: f64, opts: NumberFormatOptions| transliterator.format_number(value, opts);
+    
+    assert_eq!(format(1234.5, NumberFormatOptions::default()), "১,২৩৪.৫");
+    assert_eq!(format(123.0, NumberFormatOptions::default()), "১২৩");
+    assert_eq!(format(12345678.0, NumberFormatOptions::default()), "১,২৩,৪৫,৬৭৮");
+    assert_eq!(format(0.25, NumberFormatOptions::default()), "০.২৫");
+    
+    // Fixed decimals, percent and currency
+    let two_decimals = NumberFormatOptions { decimals: Some(2), ..Default::default() };
+    assert_eq!(format(100000.0, two_decimals.clone()), "১,০০,০০০.০০");
+    assert_eq!(format(12.5, NumberFormatOptions { percent: true, ..Default::default() }), "১২.৫%");
+    let taka = NumberFormatOptions { decimals: Some(2), currency: Some("৳".to_string()), ..Default::default() };
+    assert_eq!(format(-1500.0, taka), "-৳১,৫০০.০০");
+    
+    // A negative value that rounds to zero has no sign
+    assert_eq!(format(-0.001, two_decimals), "০.০০");
+    assert_eq!(format(-0.0, NumberFormatOptions::default()), "০");
+    
+    // Values without digits have a rendering of their own
+    assert_eq!(format(f64::NAN, NumberFormatOptions::default()), "সংখ্যা নয়");
+    assert_eq!(format(f64::INFINITY, NumberFormatOptions::default()), "∞");
+    let taka = NumberFormatOptions { currency: Some("৳".to_string()), ..Default::default() };
+    assert_eq!(format(f64::NEG_INFINITY, taka), "-৳∞");
+}
+
+#[test]
+fn test_to_bengali_ordinal() {
+    let transliterator = Transliterator::new();
+    let ordinals: Vec<String> = (1..=11).map(|n| transliterator.to_bengali_ordinal(n)).collect();
+    assert_eq!(ordinals, ["১ম", "২য়", "৩য়", "৪র্থ", "৫ম", "৬ষ্ঠ", "৭ম", "৮ম", "৯ম", "১০ম", "১১তম"]);
+    
+    // Past ten every ordinal takes তম, without digit grouping
+    assert_eq!(transliterator.to_bengali_ordinal(21), "২১তম");
+    assert_eq!(transliterator.to_bengali_ordinal(100000), "১০০০০০তম");
+    assert_eq!(transliterator.to_bengali_ordinal(0), "০তম");
+}
+
+#[test]
+fn test_transliterate_with() {
+    let transliterator = Transliterator::new();
+    
+    // Units are visited left to right, each with the engine's rendering
+    let mut visited = Vec::new();
+    let output = transliterator.transliterate_with("ami bhalo, 12", |unit, bengali| {
+        visited.push((unit.text.clone(), bengali.to_string()));
+        None
+    });
+    assert_eq!(output, transliterator.transliterate("ami bhalo, 12"));
+    assert_eq!(visited, [("a", "আ"), ("mi", "মি"), ("bha", "ভা"), ("lo", "ল")]
+        .map(|(roman, bengali)| (roman.to_string(), bengali.to_string())));
+    
+    // A replacement is written in place of the default
+    let output = transliterator.transliterate_with("ami tumi", |unit, _| {
+        (unit.text == "mi").then(|| "মী".to_string())
+    });
+    assert_eq!(output, "আমী তুমী");
+}
+
+#[test]
+fn test_transliterate_range() {
+    let transliterator = Transliterator::new();
+    
+    // The inserted 'k' joins the 'k' before it into a conjunct
+    let full = "ami bakko gai";
+    let edit = 6..7;
+    assert_eq!(&full[edit.clone()], "k");
+    assert_eq!(transliterator.transliterate("k"), "ক");
+    assert_eq!(transliterator.transliterate_range(full, edit), "বাক্ক");
+    
+    // A range across words covers each of them, and an empty range its word
+    assert_eq!(transliterator.transliterate_range(full, 2..5), "আমি বাক্ক");
+    assert_eq!(transliterator.transliterate_range(full, 11..11), "গাই");
+    assert_eq!(transliterator.transliterate_range(full, 0..full.len()), transliterator.transliterate(full));
+    
+    // Out of bounds and non-boundary ranges are adjusted rather than panicking
+    assert_eq!(transliterator.transliterate_range(full, 12..100), "গাই");
+    assert_eq!(transliterator.transliterate_range("চা ami", 1..2), "চা");
+}
+
+#[test]
+fn test_pronounced_syllable_count() {
+    let transliterator = Transliterator::new();
+    let count = |word: &str| transliterator.pronounced_syllable_count(word);
+    
+    assert_eq!(count("bhalo"), 2);
+    assert_eq!(count("nam"), 1);
+    assert_eq!(count("kk"), 1);
+    
+    assert_eq!(count("ami"), 2);
+    assert_eq!(count("kolom"), 2);
+    assert_eq!(count("biggan"), 2);
+    assert_eq!(count("songskriti"), 3);
+    assert_eq!(count("cha^d"), 1);
+    assert_eq!(count("bak,,"), 1);
+    assert_eq!(count("k"), 1);
+    assert_eq!(count("k,,"), 0);
+    assert_eq!(count(""), 0);
+}
+
+#[test]
+fn test_vowel_form_in_context() {
+    let transliterator = Transliterator::new();
+    
+    assert_eq!(transliterator.vowel_form_in_context(true, "i"), VowelForm::Dependent);
+    assert_eq!(transliterator.vowel_form_in_context(false, "i"), VowelForm::Independent);
+    assert_eq!(transliterator.vowel_form_in_context(true, "a"), VowelForm::Dependent);
+    
+    // Unmapped input has no sign to attach
+    assert_eq!(transliterator.vowel_form_in_context(true, "x"), VowelForm::Independent);
+}
+
+#[test]
+fn test_to_ipa() {
+    let transliterator = Transliterator::new();
+    let examples = [
+        ("ami", "ami"),
+        ("kotha", "kɔt̪ʰa"),        // medial inherent vowel, aspirated dental
+        ("bhalobasha", "bʱalɔbaʃa"),
+        ("ca^d", "tʃa\u{303}d̪"),    // chandrabindu nasalizes the vowel
+        ("Thakur", "ʈʰakur"),       // retroflex
+        ("ghoRa", "ɡʱɔɽa"),
+        ("bangla", "baŋla"),
+        ("bidyaloy", "bid̪ːalɔj"),  // ya-phala lengthens the consonant
+        ("k", "kɔ"),                // a lone consonant keeps its vowel
+    ];
+    
+    for (input, expected) in examples {
+        assert_eq!(transliterator.to_ipa(input), expected, "input: {}", input);
+    }
+    
+    // Whitespace and punctuation are kept
+    assert_eq!(transliterator.to_ipa("ami bhalo achi."), "ami bʱal atʃʰi.");
+}
+
+#[test]
+fn test_transliterate_with_source_map() {
+    let transliterator = Transliterator::new();
+    let (output, map) = transliterator.transliterate_with_source_map("ami jai\ntumi bhalo.");
+    assert_eq!(output, "আমি জাই\nতুমি ভাল।");
+    
+    // Any byte of a token's output maps to where the token starts
+    assert_eq!(map.output_pos_to_input(0), (1, 1));
+    assert_eq!(map.output_pos_to_input(output.find("জাই").unwrap() + 3), (1, 5));
+    assert_eq!(map.output_pos_to_input(output.find("ভাল").unwrap()), (2, 6));
+    assert_eq!(map.output_pos_to_input(output.find('।').unwrap()), (2, 11));
+    // The newline belongs to the line it ends
+    assert_eq!(map.output_pos_to_input(output.find('\n').unwrap()), (1, 8));
+}
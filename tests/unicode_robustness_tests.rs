@@ -0,0 +1,49 @@
+use obadh_engine::engine::Transliterator;
+use obadh_engine::Tokenizer;
+use proptest::prelude::*;
+
+/// Roman input mixed with multi-byte characters: accented Latin, Bengali and emoji
+fn mixed_input() -> impl Strategy<Value = String> {
+    prop::collection::vec(
+        prop_oneof![
+            3 => prop::sample::select(vec![
+                "k", "kh", "rr", "rri", "o", "O", "OI", "a", "i", "^", ":", ",,", "T``", "w", "z", "y", ".", "-", " ", "3",
+            ]).prop_map(String::from),
+            1 => any::<char>().prop_map(String::from),
+            1 => prop::sample::select(vec!["é", "ü", "ñ", "ক", "া", "্", "😀", "\u{200D}"]).prop_map(String::from),
+        ],
+        0..12,
+    ).prop_map(|parts| parts.concat())
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(512))]
+    
+    #[test]
+    fn tokenizer_does_not_panic_on_any_input(word in mixed_input()) {
+        let tokenizer = Tokenizer::new();
+        tokenizer.tokenize_word(&word);
+        tokenizer.tokenize_text(&word);
+    }
+    
+    #[test]
+    fn transliterator_does_not_panic_on_any_input(text in mixed_input()) {
+        let transliterator = Transliterator::new();
+        transliterator.transliterate(&text);
+        transliterator.transliterate_lenient(&text);
+        transliterator.annotate(&text);
+    }
+}
+
+#[test]
+fn test_accented_latin_in_word() {
+    let tokenizer = Tokenizer::new();
+    
+    // Multi-byte characters used to be sliced mid-character while matching patterns
+    let units = tokenizer.tokenize_word("kéro");
+    assert_eq!(units.iter().map(|unit| unit.text.as_str()).collect::<Vec<_>>(), ["k", "é", "ro"]);
+    
+    let tokens = tokenizer.tokenize_text("éT`` ü,,k");
+    assert_eq!(tokens[0].content, "éT``");
+    assert_eq!(tokens[2].content, "ü,,k");
+}
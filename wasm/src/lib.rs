@@ -0,0 +1,6 @@
+//! WebAssembly build of the Obadh engine
+//!
+//! The bindings are defined in [`obadh_engine::wasm`]; this crate links them
+//! into the `cdylib` that wasm-pack packages.
+
+pub use obadh_engine::wasm::*;